@@ -0,0 +1,76 @@
+/// Abstract Syntax Tree (AST) structures for the `check_components!` macro.
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::{Colon, Comma, Lt};
+use syn::{braced, Generics, Type};
+
+/// The parsed body of `check_components!`.
+///
+/// # Syntax
+///
+/// ```rust,ignore
+/// <T> MyContext<T> {
+///     FooComponent,
+///     BarComponent<T>,
+///     TypeComponent: ErrorTag,
+/// }
+/// ```
+pub struct CheckComponentsAst {
+    /// Generic parameters of the context, if any
+    pub context_generics: Generics,
+    /// The context type being checked
+    pub context_type: Type,
+    /// The components that the context should be able to use
+    pub entries: Punctuated<CheckEntryAst, Comma>,
+}
+
+/// A single component to be checked.
+pub struct CheckEntryAst {
+    /// The component name type
+    pub component_type: Type,
+    /// The `Params` given to `IsProviderFor`. If not specified, it is derived
+    /// from the generic arguments of the component type.
+    pub component_params: Option<Type>,
+}
+
+impl Parse for CheckComponentsAst {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let context_generics = if input.peek(Lt) {
+            input.parse()?
+        } else {
+            Default::default()
+        };
+
+        let context_type: Type = input.parse()?;
+
+        let entries = {
+            let entries_body;
+            braced!(entries_body in input);
+            entries_body.parse_terminated(CheckEntryAst::parse, Comma)?
+        };
+
+        Ok(Self {
+            context_generics,
+            context_type,
+            entries,
+        })
+    }
+}
+
+impl Parse for CheckEntryAst {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let component_type: Type = input.parse()?;
+
+        let component_params = if input.peek(Colon) {
+            let _: Colon = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            component_type,
+            component_params,
+        })
+    }
+}
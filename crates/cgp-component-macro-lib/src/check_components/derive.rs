@@ -0,0 +1,106 @@
+/// Generation of the compile-time checks for `check_components!`.
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{parse_quote, GenericArgument, ItemImpl, PathArguments, Type};
+
+use crate::check_components::ast::{CheckComponentsAst, CheckEntryAst};
+use crate::derive_component::is_provider_params::{lifetime_param, pack_is_provider_params};
+
+/// Processes the body of `check_components!`.
+///
+/// A hidden check trait with `CanUseComponent` as its supertrait is defined inside
+/// an anonymous `const` block, and the context implements the check trait once for
+/// each listed component. A component that is not wired for the context therefore
+/// results in an unsatisfied `CanUseComponent` bound at the corresponding entry.
+///
+/// # Arguments
+/// * `body` - The macro body, containing the context type and the list of components
+///
+/// # Returns
+/// * `syn::Result<TokenStream>` - The generated checks
+pub fn check_components(body: TokenStream) -> syn::Result<TokenStream> {
+    let ast: CheckComponentsAst = syn::parse2(body)?;
+
+    let impl_items: Vec<ItemImpl> = ast
+        .entries
+        .iter()
+        .map(|entry| derive_check_impl(&ast, entry))
+        .collect();
+
+    Ok(quote! {
+        const _: () = {
+            trait __CheckComponents<Component, Params>:
+                CanUseComponent<Component, Params>
+            {
+            }
+
+            #( #impl_items )*
+        };
+    })
+}
+
+fn derive_check_impl(ast: &CheckComponentsAst, entry: &CheckEntryAst) -> ItemImpl {
+    let component_type = &entry.component_type;
+
+    // Errors for unsatisfied bounds are reported at the self type of the impl, so
+    // the context type is re-spanned to point at the component entry being checked.
+    let context_type = respan_tokens(ast.context_type.to_token_stream(), component_type.span());
+
+    let component_params = match &entry.component_params {
+        Some(component_params) => component_params.clone(),
+        None => derive_default_params(component_type),
+    };
+
+    let (impl_generics, _, where_clause) = ast.context_generics.split_for_impl();
+
+    parse_quote! {
+        impl #impl_generics __CheckComponents< #component_type, #component_params >
+            for #context_type
+        #where_clause
+        {
+        }
+    }
+}
+
+/// Derives the default `Params` for a component from its generic arguments,
+/// e.g. `BarComponent<'a, Tag>` gives `(&'a (), Tag)`.
+fn derive_default_params(component_type: &Type) -> Type {
+    let mut params = Vec::new();
+
+    if let Type::Path(type_path) = component_type {
+        if let Some(segment) = type_path.path.segments.last() {
+            if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                for arg in args.args.iter() {
+                    match arg {
+                        GenericArgument::Lifetime(lifetime) => {
+                            params.push(lifetime_param(lifetime))
+                        }
+                        GenericArgument::Type(ty) => params.push(ty.clone()),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    pack_is_provider_params(params)
+}
+
+fn respan_tokens(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), respan_tokens(group.stream(), span));
+                new_group.set_span(group.span().located_at(span));
+                TokenTree::Group(new_group)
+            }
+            mut token => {
+                token.set_span(token.span().located_at(span));
+                token
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,36 @@
+/// Compile-time checks for the wiring of components in a context.
+///
+/// This module implements the `check_components!` macro, which asserts that a
+/// context can use a given list of components. The check follows the same
+/// `DelegateComponent` lookups as the actual component implementations, so it
+/// also covers components that are wired through presets and `DelegatesTo` traits.
+///
+/// # Module Structure
+///
+/// * [`ast`] - Abstract Syntax Tree definitions for parsing the checked components
+/// * [`derive`] - Generation of the check trait and its implementations
+///
+/// # Example
+///
+/// ```rust,ignore
+/// check_components! {
+///     MyContext {
+///         FooComponent,
+///         BarComponent<Tag>,
+///         TypeComponent: ErrorTag,
+///     }
+/// }
+///
+/// // Generates:
+/// const _: () = {
+///     trait __CheckComponents<Component, Params>: CanUseComponent<Component, Params> {}
+///
+///     impl __CheckComponents<FooComponent, ()> for MyContext {}
+///     impl __CheckComponents<BarComponent<Tag>, Tag> for MyContext {}
+///     impl __CheckComponents<TypeComponent, ErrorTag> for MyContext {}
+/// };
+/// ```
+pub mod ast;
+pub mod derive;
+
+pub use derive::check_components;
@@ -10,7 +10,7 @@ use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::token::{Bracket, Colon, Comma, Lt};
-use syn::{braced, bracketed, Generics, Token, Type};
+use syn::{braced, bracketed, Error, Generics, Token, Type, WherePredicate};
 
 /// Root AST node representing a complete component delegation specification.
 ///
@@ -44,7 +44,8 @@ pub struct DelegateEntriesAst {
 /// Single delegation entry in the AST.
 ///
 /// Specifies a mapping between a set of components and their source type
-/// for delegation.
+/// for delegation. The where clause of an entry, if any, is merged into the
+/// generics of each of its components.
 ///
 /// # Fields
 ///
@@ -138,9 +139,13 @@ impl Parse for DelegateEntriesAst {
 /// ```text
 /// comp: source_type
 /// ```
+/// Both forms can be followed by a braced where clause:
+/// ```text
+/// <T> comp<T>: source_type<T> where { T: Constraint }
+/// ```
 impl Parse for DelegateEntryAst {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let components = if input.peek(Bracket) {
+        let mut components = if input.peek(Bracket) {
             let components_body;
             bracketed!(components_body in input);
             components_body.parse_terminated(ComponentAst::parse, Token![,])?
@@ -153,6 +158,31 @@ impl Parse for DelegateEntryAst {
 
         let source: Type = input.parse()?;
 
+        if input.peek(Token![where]) {
+            let where_token: Token![where] = input.parse()?;
+
+            let predicates: Punctuated<WherePredicate, Comma> = {
+                let predicates_body;
+                braced!(predicates_body in input);
+                predicates_body.parse_terminated(WherePredicate::parse, Comma)?
+            };
+
+            if predicates.is_empty() {
+                return Err(Error::new(
+                    where_token.span,
+                    "expect at least one predicate in the where clause",
+                ));
+            }
+
+            for component in components.iter_mut() {
+                component
+                    .component_generics
+                    .make_where_clause()
+                    .predicates
+                    .extend(predicates.clone());
+            }
+        }
+
         Ok(Self { components, source })
     }
 }
@@ -3,6 +3,7 @@ use proc_macro2::TokenStream;
 use quote::ToTokens;
 
 use crate::delegate_components::ast::DelegateComponentsAst;
+use crate::delegate_components::duplicate::check_duplicate_components;
 use crate::delegate_components::impl_delegate::impl_delegate_components;
 
 /// Processes component delegation macro by generating the necessary trait implementations.
@@ -24,6 +25,8 @@ use crate::delegate_components::impl_delegate::impl_delegate_components;
 pub fn delegate_components(body: TokenStream) -> syn::Result<TokenStream> {
     let ast: DelegateComponentsAst = syn::parse2(body)?;
 
+    check_duplicate_components(&ast.delegate_entries)?;
+
     let impl_items = impl_delegate_components(
         &ast.target_type,
         &ast.target_generics,
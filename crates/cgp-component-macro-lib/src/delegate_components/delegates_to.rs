@@ -1,9 +1,11 @@
 /// Functionality for defining delegate component trait bounds and trait implementations
 use syn::punctuated::Punctuated;
-use syn::token::Plus;
-use syn::{parse_quote, Generics, Ident, ItemImpl, ItemTrait, Type, TypeParamBound};
+use syn::token::{Comma, Plus};
+use syn::{
+    parse_quote, Generics, Ident, ItemImpl, ItemTrait, Type, TypeParamBound, WherePredicate,
+};
 
-use crate::delegate_components::ast::DelegateEntriesAst;
+use crate::delegate_components::ast::{ComponentAst, DelegateEntriesAst};
 
 /// Generates trait bounds for delegated components.
 ///
@@ -29,7 +31,7 @@ pub fn define_delegate_component_trait_bounds(
 ) -> Punctuated<TypeParamBound, Plus> {
     let mut trait_bounds: Punctuated<TypeParamBound, Plus> = Punctuated::new();
 
-    for component in delegate_entries.all_components() {
+    for component in non_generic_components(delegate_entries) {
         let component_type = &component.component_type;
         let trait_bound: TypeParamBound = parse_quote!(
             DelegateComponent<#component_type, Delegate = #target_type>
@@ -40,6 +42,31 @@ pub fn define_delegate_component_trait_bounds(
     trait_bounds
 }
 
+/// Collects the where predicates attached to the delegated components, which
+/// need to hold for the `DelegateComponent` bounds of the components.
+pub fn define_delegate_component_predicates(
+    delegate_entries: &DelegateEntriesAst,
+) -> Punctuated<WherePredicate, Comma> {
+    non_generic_components(delegate_entries)
+        .filter_map(|component| component.component_generics.where_clause)
+        .flat_map(|where_clause| where_clause.predicates)
+        .collect()
+}
+
+/// Returns the components that have no generic parameters of their own.
+///
+/// A component such as `<T> FooComponent<T>` is delegated for all `T`, which
+/// cannot be expressed as a supertrait bound, so such components are not
+/// included in the bounds of the delegates-to trait.
+fn non_generic_components(
+    delegate_entries: &DelegateEntriesAst,
+) -> impl Iterator<Item = ComponentAst> {
+    delegate_entries
+        .all_components()
+        .into_iter()
+        .filter(|component| component.component_generics.params.is_empty())
+}
+
 /// Defines a trait and its implementation for component delegation.
 ///
 /// This function creates:
@@ -72,8 +99,13 @@ pub fn define_delegates_to_trait(
 ) -> (ItemTrait, ItemImpl) {
     let trait_bounds = define_delegate_component_trait_bounds(target_type, delegate_entries);
 
+    let component_predicates = define_delegate_component_predicates(delegate_entries);
+
     let item_trait = parse_quote! {
-        pub trait #trait_name #target_generics: #trait_bounds {}
+        pub trait #trait_name #target_generics: #trait_bounds
+        where
+            #component_predicates
+        {}
     };
 
     let mut impl_generics = target_generics.clone();
@@ -84,7 +116,8 @@ pub fn define_delegates_to_trait(
     let item_impl = parse_quote! {
         impl #impl_generics #trait_name #type_generics  for Components
         where
-            Components: #trait_bounds
+            Components: #trait_bounds,
+            #component_predicates
         {}
     };
 
@@ -0,0 +1,102 @@
+/// Detection of components that are delegated more than once.
+///
+/// Delegating the same component twice would otherwise result in conflicting
+/// `DelegateComponent` implementations, with the error pointing to the macro output.
+use std::collections::BTreeMap;
+
+use proc_macro2::{Group, Ident, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{Error, GenericParam};
+
+use crate::delegate_components::ast::{ComponentAst, DelegateEntriesAst};
+
+/// Checks that each component appears at most once in the delegate entries.
+///
+/// Components are compared by their normalized tokens, with the generic parameters
+/// of each component renamed by position. As a result, `<T> FooComponent<T>` and
+/// `<U> FooComponent<U>` are considered to be the same component.
+///
+/// # Returns
+///
+/// Returns an error covering all duplicates, which points at both the duplicate and
+/// the first occurrence of the component.
+pub fn check_duplicate_components(delegate_entries: &DelegateEntriesAst) -> syn::Result<()> {
+    let mut seen: BTreeMap<String, ComponentAst> = BTreeMap::new();
+    let mut errors: Option<Error> = None;
+
+    for component in delegate_entries.all_components() {
+        let key = normalize_component(&component);
+
+        match seen.get(&key) {
+            Some(first) => {
+                let mut error = Error::new_spanned(
+                    &component.component_type,
+                    format!(
+                        "duplicate component `{}`: each component can only be delegated once",
+                        component.component_type.to_token_stream(),
+                    ),
+                );
+
+                error.combine(Error::new_spanned(
+                    &first.component_type,
+                    format!(
+                        "`{}` is first delegated here",
+                        first.component_type.to_token_stream()
+                    ),
+                ));
+
+                match &mut errors {
+                    Some(errors) => errors.combine(error),
+                    None => errors = Some(error),
+                }
+            }
+            None => {
+                seen.insert(key, component);
+            }
+        }
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(()),
+    }
+}
+
+/// Normalizes a component into a string that can be compared for equality,
+/// by renaming the component's generic parameters according to their position.
+pub fn normalize_component(component: &ComponentAst) -> String {
+    let renames: BTreeMap<String, String> = component
+        .component_generics
+        .params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let ident = match param {
+                GenericParam::Type(param) => &param.ident,
+                GenericParam::Const(param) => &param.ident,
+                GenericParam::Lifetime(param) => &param.lifetime.ident,
+            };
+
+            (ident.to_string(), format!("__Param{i}"))
+        })
+        .collect();
+
+    rename_idents(&renames, component.component_type.to_token_stream()).to_string()
+}
+
+fn rename_idents(renames: &BTreeMap<String, String>, tokens: TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) => match renames.get(&ident.to_string()) {
+                Some(renamed) => TokenTree::Ident(Ident::new(renamed, ident.span())),
+                None => TokenTree::Ident(ident),
+            },
+            TokenTree::Group(group) => TokenTree::Group(Group::new(
+                group.delimiter(),
+                rename_idents(renames, group.stream()),
+            )),
+            token => token,
+        })
+        .collect()
+}
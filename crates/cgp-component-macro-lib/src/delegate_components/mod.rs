@@ -10,6 +10,7 @@
 /// * [`define_struct`] - Utilities for generating struct definitions with proper generic handling
 /// * [`delegate`] - Core delegation implementation and macro expansion
 /// * [`delegates_to`] - Trait definitions and bounds for delegation relationships
+/// * [`duplicate`] - Detection of components that are delegated more than once
 /// * [`impl_delegate`] - Implementation details for component delegation
 /// * [`merge_generics`] - Utilities for combining generic parameters from multiple sources
 ///
@@ -34,6 +35,7 @@ pub mod ast;
 pub mod define_struct;
 pub mod delegate;
 pub mod delegates_to;
+pub mod duplicate;
 pub mod impl_delegate;
 pub mod merge_generics;
 
@@ -1,6 +1,6 @@
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
-use syn::{parse_quote, Ident, ItemStruct};
+use syn::{parse_quote, Ident, ItemStruct, Type};
 
 /// Generates a component name struct definition with optional generic parameters.
 ///
@@ -42,3 +42,15 @@ pub fn derive_component_name_struct(
         parse_quote!(pub struct #component_name < #component_params > ( pub core::marker::PhantomData<( #component_params )> );)
     }
 }
+
+/// Builds the type referring to the component name struct, e.g. `MyComponent<T, U>`.
+pub fn derive_component_type(
+    component_name: &Ident,
+    component_params: &Punctuated<Ident, Comma>,
+) -> Type {
+    if component_params.is_empty() {
+        parse_quote!( #component_name )
+    } else {
+        parse_quote!( #component_name < #component_params > )
+    }
+}
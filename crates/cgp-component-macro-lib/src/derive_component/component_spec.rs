@@ -4,7 +4,7 @@ use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::token::{Comma, Gt, Lt};
-use syn::{Error, Ident};
+use syn::{Error, Ident, Type};
 
 use crate::derive_component::entry::Entries;
 
@@ -22,6 +22,8 @@ pub struct ComponentSpec {
     pub component_name: Ident,
     /// Generic parameters for the component
     pub component_params: Punctuated<Ident, Comma>,
+    /// Type used to dispatch the `UseDelegate` provider implementation, if requested
+    pub delegate_key: Option<Type>,
 }
 
 /// Specification for a component's name and its generic parameters.
@@ -36,25 +38,49 @@ pub struct ComponentNameSpec {
     pub component_params: Punctuated<Ident, Comma>,
 }
 
+/// Keys that are accepted in the component specification.
+const ACCEPTED_KEYS: [&str; 4] = ["name", "context", "provider", "derive_delegate"];
+
 /// Parser implementation for ComponentSpec
 ///
+/// Unknown keys, duplicate keys, and values of the wrong shape are rejected
+/// with an error pointing at the offending tokens.
+///
 /// Parses component specifications in the format:
 /// ```ignore
 /// #[component(
 ///     provider = MyProvider,
 ///     context = MyContext,
-///     name = MyComponent<T, U>
+///     name = MyComponent<T, U>,
+///     derive_delegate = [T],
 /// )]
 /// ```
 impl Parse for ComponentSpec {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let Entries { entries } = input.parse()?;
 
+        for key in entries.keys() {
+            if !ACCEPTED_KEYS.iter().any(|accepted| key == accepted) {
+                return Err(Error::new(
+                    key.span(),
+                    format!(
+                        "unknown key `{key}`: accepted keys are {}",
+                        format_accepted_keys()
+                    ),
+                ));
+            }
+        }
+
         let context_type: Ident = {
             let raw_context_type = entries.get(&Ident::new("context", Span::call_site()));
 
             if let Some(context_type) = raw_context_type {
-                syn::parse2(context_type.to_token_stream())?
+                syn::parse2(context_type.to_token_stream()).map_err(|_| {
+                    Error::new_spanned(
+                        context_type,
+                        "expect `context` to be a plain identifier, e.g. `context: Context`",
+                    )
+                })?
             } else {
                 Ident::new("Context", Span::call_site())
             }
@@ -63,9 +89,19 @@ impl Parse for ComponentSpec {
         let provider_name: Ident = {
             let raw_provider_name = entries
                 .get(&Ident::new("provider", Span::call_site()))
-                .ok_or_else(|| Error::new(input.span(), "expect provider name to be given"))?;
-
-            syn::parse2(raw_provider_name.to_token_stream())?
+                .ok_or_else(|| {
+                    Error::new(
+                        Span::call_site(),
+                        "missing `provider` entry: expect provider name to be given, e.g. `#[cgp_component { provider: FooProvider }]`",
+                    )
+                })?;
+
+            syn::parse2(raw_provider_name.to_token_stream()).map_err(|_| {
+                Error::new_spanned(
+                    raw_provider_name,
+                    "expect `provider` to be a plain identifier, e.g. `provider: FooProvider`",
+                )
+            })?
         };
 
         let (component_name, component_params) = {
@@ -75,7 +111,12 @@ impl Parse for ComponentSpec {
                 let ComponentNameSpec {
                     component_name,
                     component_params,
-                } = syn::parse2(raw_component_name.to_token_stream())?;
+                } = syn::parse2(raw_component_name.to_token_stream()).map_err(|_| {
+                    Error::new_spanned(
+                        raw_component_name,
+                        "expect `name` to be an identifier with optional generic parameters, e.g. `name: FooComponent<Tag>`",
+                    )
+                })?;
                 (component_name, component_params)
             } else {
                 (
@@ -85,15 +126,35 @@ impl Parse for ComponentSpec {
             }
         };
 
+        let delegate_key = match entries.get(&Ident::new("derive_delegate", Span::call_site())) {
+            Some(Type::Slice(slice)) => Some(slice.elem.as_ref().clone()),
+            Some(raw_delegate_key) => {
+                return Err(Error::new_spanned(
+                    raw_delegate_key,
+                    "expect `derive_delegate` to be a type in square brackets, e.g. `derive_delegate: [Tag]`",
+                ));
+            }
+            None => None,
+        };
+
         Ok(ComponentSpec {
             component_name,
             provider_name,
             context_type,
             component_params,
+            delegate_key,
         })
     }
 }
 
+fn format_accepted_keys() -> String {
+    ACCEPTED_KEYS
+        .iter()
+        .map(|key| format!("`{key}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Parser implementation for ComponentNameSpec
 ///
 /// Parses component names in the format:
@@ -5,10 +5,11 @@
 use syn::punctuated::Punctuated;
 use syn::token::{Brace, Comma, For, Impl, Plus};
 use syn::{
-    parse_quote, GenericParam, Ident, ImplItem, ItemImpl, ItemTrait, Path, TraitItem,
-    TypeParamBound,
+    parse_quote, Error, GenericArgument, GenericParam, Ident, ImplItem, ItemImpl, ItemTrait, Path,
+    TraitItem, TypeParamBound,
 };
 
+use crate::derive_component::delegate_const::derive_delegate_const_impl;
 use crate::derive_component::delegate_fn::derive_delegated_fn_impl;
 use crate::derive_component::delegate_type::derive_delegate_type_impl;
 
@@ -23,7 +24,8 @@ use crate::derive_component::delegate_type::derive_delegate_type_impl;
 /// * `context_type` - The context type that will implement the consumer trait
 ///
 /// # Returns
-/// * `ItemImpl` - The generated implementation block
+/// * `syn::Result<ItemImpl>` - The generated implementation block, or an error
+///   if the consumer trait contains unsupported items
 ///
 /// # Generated Code Example
 /// ```ignore
@@ -53,22 +55,24 @@ pub fn derive_consumer_impl(
     consumer_trait: &ItemTrait,
     provider_name: &Ident,
     context_type: &Ident,
-) -> ItemImpl {
+) -> syn::Result<ItemImpl> {
     let consumer_name = &consumer_trait.ident;
 
     let consumer_generic_args = {
-        let mut generic_args: Punctuated<Ident, Comma> = Punctuated::new();
+        let mut generic_args: Punctuated<GenericArgument, Comma> = Punctuated::new();
 
         for param in consumer_trait.generics.params.iter() {
             match param {
                 GenericParam::Type(ty) => {
-                    generic_args.push(ty.ident.clone());
+                    let ident = &ty.ident;
+                    generic_args.push(parse_quote!(#ident));
                 }
                 GenericParam::Const(arg) => {
-                    generic_args.push(arg.ident.clone());
+                    let ident = &arg.ident;
+                    generic_args.push(parse_quote!(#ident));
                 }
-                GenericParam::Lifetime(_life) => {
-                    unimplemented!()
+                GenericParam::Lifetime(life) => {
+                    generic_args.push(GenericArgument::Lifetime(life.lifetime.clone()));
                 }
             }
         }
@@ -76,10 +80,13 @@ pub fn derive_consumer_impl(
         generic_args
     };
 
+    // Lifetime arguments must come first, so the context type is placed right after them.
+    let lifetime_count = consumer_trait.generics.lifetimes().count();
+
     let provider_generic_args = {
         let mut generic_args = consumer_generic_args.clone();
 
-        generic_args.insert(0, parse_quote!(#context_type));
+        generic_args.insert(lifetime_count, parse_quote!(#context_type));
 
         generic_args
     };
@@ -87,7 +94,9 @@ pub fn derive_consumer_impl(
     let impl_generics = {
         let mut impl_generics = consumer_trait.generics.clone();
 
-        impl_generics.params.insert(0, parse_quote!(#context_type));
+        impl_generics
+            .params
+            .insert(lifetime_count, parse_quote!(#context_type));
 
         {
             let supertrait_constraints = consumer_trait.supertraits.clone();
@@ -170,13 +179,30 @@ pub fn derive_consumer_impl(
 
                 impl_items.push(ImplItem::Type(impl_type));
             }
-            _ => {}
+            TraitItem::Const(trait_const) => {
+                let const_name = &trait_const.ident;
+
+                let impl_const = derive_delegate_const_impl(
+                    trait_const,
+                    parse_quote!(
+                        < #context_type :: Components as #provider_name < #provider_generic_args > > :: #const_name
+                    ),
+                );
+
+                impl_items.push(ImplItem::Const(impl_const));
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    trait_item,
+                    "unsupported trait item: #[cgp_component] only supports methods, associated types and associated constants",
+                ));
+            }
         }
     }
 
     let trait_path: Path = parse_quote!( #consumer_name < #consumer_generic_args > );
 
-    ItemImpl {
+    Ok(ItemImpl {
         attrs: consumer_trait.attrs.clone(),
         defaultness: None,
         unsafety: consumer_trait.unsafety,
@@ -186,5 +212,5 @@ pub fn derive_consumer_impl(
         self_ty: Box::new(parse_quote!(#context_type)),
         brace_token: Brace::default(),
         items: impl_items,
-    }
+    })
 }
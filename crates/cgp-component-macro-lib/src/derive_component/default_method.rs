@@ -0,0 +1,60 @@
+/// Handling of methods that keep their default implementation in the consumer trait.
+///
+/// Methods marked with `#[cgp_default]` are not routed through the provider trait.
+/// Instead, their default body is kept as is in the consumer trait, which is useful
+/// for defining helper methods on top of the delegated methods.
+use syn::{Error, ItemTrait, TraitItem};
+
+/// Name of the attribute used to mark methods that keep their default body.
+pub const CGP_DEFAULT_ATTR: &str = "cgp_default";
+
+/// Splits the methods marked with `#[cgp_default]` out of a consumer trait.
+///
+/// # Arguments
+/// * `consumer_trait` - The consumer trait definition given to the macro
+///
+/// # Returns
+/// * `syn::Result<(ItemTrait, ItemTrait)>` - A pair containing:
+///   - the consumer trait to be emitted, with the `#[cgp_default]` attributes stripped
+///   - the trait containing only the items to be delegated through the provider
+///
+/// # Errors
+/// Returns an error if a method marked with `#[cgp_default]` has no default body.
+pub fn split_default_methods(consumer_trait: &ItemTrait) -> syn::Result<(ItemTrait, ItemTrait)> {
+    let mut stripped_trait = consumer_trait.clone();
+    let mut delegated_trait = consumer_trait.clone();
+
+    stripped_trait.items.clear();
+    delegated_trait.items.clear();
+
+    for item in consumer_trait.items.iter() {
+        if let TraitItem::Fn(trait_fn) = item {
+            let (default_attrs, attrs): (Vec<_>, Vec<_>) = trait_fn
+                .attrs
+                .iter()
+                .cloned()
+                .partition(|attr| attr.path().is_ident(CGP_DEFAULT_ATTR));
+
+            if let Some(default_attr) = default_attrs.first() {
+                if trait_fn.default.is_none() {
+                    return Err(Error::new_spanned(
+                        default_attr,
+                        "methods marked with #[cgp_default] must provide a default body",
+                    ));
+                }
+
+                let mut trait_fn = trait_fn.clone();
+                trait_fn.attrs = attrs;
+
+                stripped_trait.items.push(TraitItem::Fn(trait_fn));
+
+                continue;
+            }
+        }
+
+        stripped_trait.items.push(item.clone());
+        delegated_trait.items.push(item.clone());
+    }
+
+    Ok((stripped_trait, delegated_trait))
+}
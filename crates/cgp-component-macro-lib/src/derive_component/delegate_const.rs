@@ -0,0 +1,51 @@
+/// Utilities for deriving associated constant implementations for delegated components.
+///
+/// This module provides functionality to create implementations of associated constants
+/// when delegating component traits to other types.
+use syn::token::Eq;
+use syn::{Expr, ImplItemConst, TraitItemConst, Visibility};
+
+/// Derives an implementation of an associated constant for a delegated component.
+///
+/// Creates an implementation that sets a trait's associated constant to the specified
+/// delegated expression, preserving attributes and the declared type from the original
+/// trait definition.
+///
+/// # Arguments
+///
+/// * `trait_const` - The associated constant definition from the trait
+/// * `delegated_expr` - The expression referring to the delegated constant
+///
+/// # Returns
+///
+/// An `ImplItemConst` representing the associated constant implementation
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// // For a trait definition:
+/// trait HasLimit {
+///     const LIMIT: usize;
+/// }
+///
+/// // This function generates:
+/// const LIMIT: usize = <Context::Components as LimitProvider<Context>>::LIMIT;
+/// ```
+pub fn derive_delegate_const_impl(
+    trait_const: &TraitItemConst,
+    delegated_expr: Expr,
+) -> ImplItemConst {
+    ImplItemConst {
+        attrs: trait_const.attrs.clone(),
+        vis: Visibility::Inherited,
+        defaultness: None,
+        const_token: trait_const.const_token,
+        ident: trait_const.ident.clone(),
+        generics: trait_const.generics.clone(),
+        colon_token: trait_const.colon_token,
+        ty: trait_const.ty.clone(),
+        eq_token: Eq::default(),
+        expr: delegated_expr,
+        semi_token: trait_const.semi_token,
+    }
+}
@@ -6,11 +6,15 @@ use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::ItemTrait;
 
-use crate::derive_component::component_name::derive_component_name_struct;
+use crate::derive_component::component_name::{
+    derive_component_name_struct, derive_component_type,
+};
 use crate::derive_component::component_spec::ComponentSpec;
 use crate::derive_component::consumer_impl::derive_consumer_impl;
+use crate::derive_component::default_method::split_default_methods;
 use crate::derive_component::provider_impl::derive_provider_impl;
 use crate::derive_component::provider_trait::derive_provider_trait;
+use crate::derive_component::use_delegate_impl::derive_use_delegate_impl;
 
 /// Derives a complete component implementation from a trait definition.
 ///
@@ -20,13 +24,15 @@ use crate::derive_component::provider_trait::derive_provider_trait;
 /// - A provider trait
 /// - Consumer implementations
 /// - Provider implementations
+/// - A `UseDelegate` provider implementation, if `derive_delegate` is specified
 ///
 /// # Arguments
 /// * `attr` - Attribute tokens containing component specification
 /// * `item` - The input trait definition tokens
 ///
 /// # Returns
-/// * `TokenStream` - Generated code containing all component implementations
+/// * `syn::Result<TokenStream>` - Generated code containing all component implementations,
+///   or a spanned error if the specification or the trait is malformed
 ///
 /// # Generated Items
 /// For a component named 'MyComponent':
@@ -43,10 +49,12 @@ use crate::derive_component::provider_trait::derive_provider_trait;
 /// // Provider implementation
 /// impl<T> MyComponentProvider<Context> for T where T: Provider<MyComponent> { ... }
 /// ```
-pub fn derive_component(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let spec: ComponentSpec = syn::parse2(attr).unwrap();
+pub fn derive_component(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let spec: ComponentSpec = syn::parse2(attr)?;
 
-    let consumer_trait: ItemTrait = syn::parse2(item).unwrap();
+    let raw_consumer_trait: ItemTrait = syn::parse2(item)?;
+
+    let (consumer_trait, delegated_trait) = split_default_methods(&raw_consumer_trait)?;
 
     let provider_name = &spec.provider_name;
     let context_type = &spec.context_type;
@@ -54,10 +62,16 @@ pub fn derive_component(attr: TokenStream, item: TokenStream) -> TokenStream {
     let component_struct =
         derive_component_name_struct(&spec.component_name, &spec.component_params);
 
-    let provider_trait =
-        derive_provider_trait(&consumer_trait, provider_name, context_type).unwrap();
+    let component_type = derive_component_type(&spec.component_name, &spec.component_params);
+
+    let provider_trait = derive_provider_trait(
+        &delegated_trait,
+        provider_name,
+        context_type,
+        &component_type,
+    )?;
 
-    let consumer_impl = derive_consumer_impl(&consumer_trait, provider_name, context_type);
+    let consumer_impl = derive_consumer_impl(&delegated_trait, provider_name, context_type)?;
 
     let provider_impl = derive_provider_impl(
         &provider_trait,
@@ -65,12 +79,20 @@ pub fn derive_component(attr: TokenStream, item: TokenStream) -> TokenStream {
         &spec.component_params,
     );
 
+    let use_delegate_impl = spec.delegate_key.as_ref().map(|delegate_key| {
+        derive_use_delegate_impl(&provider_trait, context_type, &component_type, delegate_key)
+    });
+
     let mut output = consumer_trait.to_token_stream();
 
     output.extend(component_struct.to_token_stream());
     output.extend(provider_trait.to_token_stream());
     output.extend(consumer_impl.to_token_stream());
     output.extend(provider_impl.to_token_stream());
+    if let Some((use_delegate_impl, is_provider_impl)) = use_delegate_impl {
+        output.extend(use_delegate_impl.to_token_stream());
+        output.extend(is_provider_impl.to_token_stream());
+    }
 
-    output
+    Ok(output)
 }
@@ -8,7 +8,7 @@ use std::collections::BTreeMap;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::token::{Colon, Comma};
-use syn::{Ident, Type};
+use syn::{Error, Ident, Type};
 
 /// A single key-value entry in a component specification.
 ///
@@ -54,7 +54,8 @@ impl Parse for Entry {
 /// # Note
 ///
 /// Using BTreeMap ensures consistent ordering of entries, which is important
-/// for deterministic code generation.
+/// for deterministic code generation. Specifying the same key more than once
+/// is rejected with an error pointing at the repeated key.
 pub struct Entries {
     pub entries: BTreeMap<Ident, Type>,
 }
@@ -63,8 +64,18 @@ impl Parse for Entries {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let entry_list: Punctuated<Entry, Comma> = Punctuated::parse_terminated(input)?;
 
-        let entries =
-            BTreeMap::from_iter(entry_list.into_iter().map(|entry| (entry.key, entry.value)));
+        let mut entries = BTreeMap::new();
+
+        for Entry { key, value } in entry_list {
+            if entries.contains_key(&key) {
+                return Err(Error::new(
+                    key.span(),
+                    format!("duplicate key `{key}`: each key can only be specified once"),
+                ));
+            }
+
+            entries.insert(key, value);
+        }
 
         Ok(Entries { entries })
     }
@@ -0,0 +1,52 @@
+/// Construction of the `Params` argument used by `IsProviderFor`.
+///
+/// The generic parameters of a provider trait, other than the context type,
+/// are packed into a single type so that `IsProviderFor` can have a fixed
+/// number of generic parameters.
+use syn::{parse_quote, GenericParam, Generics, Lifetime, Type};
+
+/// Derives the `Params` type for `IsProviderFor` from the generic parameters
+/// of a consumer trait.
+///
+/// Type parameters are used as is, and lifetime parameters are represented as
+/// `&'a ()`. Const generic parameters cannot be represented in a type and are
+/// skipped.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// trait HasFoo                       // => ()
+/// trait HasFoo<Tag>                  // => Tag
+/// trait HasFoo<'a, Tag, const N: usize> // => (&'a (), Tag)
+/// ```
+pub fn derive_is_provider_params(generics: &Generics) -> Type {
+    let params = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                Some(parse_quote!(#ident))
+            }
+            GenericParam::Lifetime(life) => Some(lifetime_param(&life.lifetime)),
+            GenericParam::Const(_) => None,
+        })
+        .collect();
+
+    pack_is_provider_params(params)
+}
+
+/// Represents a lifetime parameter as a type that can be stored in `Params`.
+pub fn lifetime_param(lifetime: &Lifetime) -> Type {
+    parse_quote!( & #lifetime () )
+}
+
+/// Packs the given parameter types into a single type, using `()` for no
+/// parameters, the type itself for one parameter, and a tuple otherwise.
+pub fn pack_is_provider_params(params: Vec<Type>) -> Type {
+    match params.as_slice() {
+        [] => parse_quote!(()),
+        [param] => param.clone(),
+        params => parse_quote!( ( #( #params ),* ) ),
+    }
+}
@@ -7,15 +7,19 @@
 pub mod component_name;
 pub mod component_spec;
 pub mod consumer_impl;
+pub mod default_method;
+pub mod delegate_const;
 pub mod delegate_fn;
 pub mod delegate_type;
 pub mod derive;
 pub mod entry;
+pub mod is_provider_params;
 pub mod provider_impl;
 pub mod provider_trait;
 pub mod replace_self_receiver;
 pub mod replace_self_type;
 pub mod signature_args;
 pub mod snake_case;
+pub mod use_delegate_impl;
 
 pub use derive::derive_component;
@@ -6,10 +6,11 @@ use proc_macro2::Span;
 use syn::punctuated::Punctuated;
 use syn::token::{Brace, Comma, For, Impl, Plus};
 use syn::{
-    parse_quote, GenericParam, Ident, ImplItem, ItemImpl, ItemTrait, Path, TraitItem,
-    TypeParamBound,
+    parse_quote, GenericArgument, GenericParam, Ident, ImplItem, ItemImpl, ItemTrait, Path,
+    TraitItem, TypeParamBound, TypePath,
 };
 
+use crate::derive_component::delegate_const::derive_delegate_const_impl;
 use crate::derive_component::delegate_fn::derive_delegated_fn_impl;
 use crate::derive_component::delegate_type::derive_delegate_type_impl;
 
@@ -58,32 +59,17 @@ pub fn derive_provider_impl(
 
     let component_type = Ident::new("Component", Span::call_site());
 
-    let provider_generic_args = {
-        let mut generic_args: Punctuated<Ident, Comma> = Punctuated::new();
-
-        for param in provider_trait.generics.params.iter() {
-            match param {
-                GenericParam::Type(ty) => {
-                    generic_args.push(ty.ident.clone());
-                }
-                GenericParam::Const(arg) => {
-                    generic_args.push(arg.ident.clone());
-                }
-                GenericParam::Lifetime(_life) => {
-                    unimplemented!()
-                }
-            }
-        }
-
-        generic_args
-    };
+    let provider_generic_args = derive_provider_generic_args(provider_trait);
 
     let impl_generics = {
         let mut impl_generics = provider_trait.generics.clone();
 
+        // Lifetime parameters must come first, so the component type is placed right after them.
+        let lifetime_count = provider_trait.generics.lifetimes().count();
+
         impl_generics
             .params
-            .insert(0, parse_quote!(#component_type));
+            .insert(lifetime_count, parse_quote!(#component_type));
 
         {
             let delegate_constraint: Punctuated<TypeParamBound, Plus> = parse_quote! {
@@ -114,15 +100,68 @@ pub fn derive_provider_impl(
         impl_generics
     };
 
+    let impl_items = derive_delegated_provider_items(
+        provider_trait,
+        &provider_generic_args,
+        &parse_quote!(#component_type :: Delegate),
+    );
+
+    let trait_path: Path = parse_quote!( #provider_name < #provider_generic_args > );
+
+    ItemImpl {
+        attrs: provider_trait.attrs.clone(),
+        defaultness: None,
+        unsafety: provider_trait.unsafety,
+        impl_token: Impl::default(),
+        generics: impl_generics,
+        trait_: Some((None, trait_path, For::default())),
+        self_ty: Box::new(parse_quote!(#component_type)),
+        brace_token: Brace::default(),
+        items: impl_items,
+    }
+}
+
+/// Builds the generic arguments for referring to a provider trait from its own
+/// generic parameters, e.g. `<'a, Context, T, N>` for `trait P<'a, Context, T, const N: usize>`.
+pub fn derive_provider_generic_args(
+    provider_trait: &ItemTrait,
+) -> Punctuated<GenericArgument, Comma> {
+    let mut generic_args: Punctuated<GenericArgument, Comma> = Punctuated::new();
+
+    for param in provider_trait.generics.params.iter() {
+        match param {
+            GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                generic_args.push(parse_quote!(#ident));
+            }
+            GenericParam::Const(arg) => {
+                let ident = &arg.ident;
+                generic_args.push(parse_quote!(#ident));
+            }
+            GenericParam::Lifetime(life) => {
+                generic_args.push(GenericArgument::Lifetime(life.lifetime.clone()));
+            }
+        }
+    }
+
+    generic_args
+}
+
+/// Derives the items of a provider implementation that forward every method,
+/// associated type and associated constant to the given delegate type.
+pub fn derive_delegated_provider_items(
+    provider_trait: &ItemTrait,
+    provider_generic_args: &Punctuated<GenericArgument, Comma>,
+    delegate_type: &TypePath,
+) -> Vec<ImplItem> {
+    let provider_name = &provider_trait.ident;
+
     let mut impl_items: Vec<ImplItem> = Vec::new();
 
     for trait_item in provider_trait.items.iter() {
         match trait_item {
             TraitItem::Fn(trait_fn) => {
-                let impl_fn = derive_delegated_fn_impl(
-                    &trait_fn.sig,
-                    &parse_quote!(#component_type :: Delegate),
-                );
+                let impl_fn = derive_delegated_fn_impl(&trait_fn.sig, delegate_type);
 
                 impl_items.push(ImplItem::Fn(impl_fn))
             }
@@ -144,27 +183,27 @@ pub fn derive_provider_impl(
                 let impl_type = derive_delegate_type_impl(
                     trait_type,
                     parse_quote!(
-                        < #component_type :: Delegate as #provider_name < #provider_generic_args > > :: #type_name #type_generics
+                        < #delegate_type as #provider_name < #provider_generic_args > > :: #type_name #type_generics
                     ),
                 );
 
                 impl_items.push(ImplItem::Type(impl_type));
             }
+            TraitItem::Const(trait_const) => {
+                let const_name = &trait_const.ident;
+
+                let impl_const = derive_delegate_const_impl(
+                    trait_const,
+                    parse_quote!(
+                        < #delegate_type as #provider_name < #provider_generic_args > > :: #const_name
+                    ),
+                );
+
+                impl_items.push(ImplItem::Const(impl_const));
+            }
             _ => {}
         }
     }
 
-    let trait_path: Path = parse_quote!( #provider_name < #provider_generic_args > );
-
-    ItemImpl {
-        attrs: provider_trait.attrs.clone(),
-        defaultness: None,
-        unsafety: provider_trait.unsafety,
-        impl_token: Impl::default(),
-        generics: impl_generics,
-        trait_: Some((None, trait_path, For::default())),
-        self_ty: Box::new(parse_quote!(#component_type)),
-        brace_token: Brace::default(),
-        items: impl_items,
-    }
+    impl_items
 }
@@ -3,8 +3,10 @@
 /// This module handles the generation of provider traits from consumer traits,
 /// including the transformation of self types and receivers into context types.
 use syn::punctuated::Punctuated;
-use syn::{parse_quote, Ident, ItemTrait, TraitItem};
+use syn::token::Colon;
+use syn::{parse_quote, Ident, ItemTrait, TraitItem, Type};
 
+use crate::derive_component::is_provider_params::derive_is_provider_params;
 use crate::derive_component::replace_self_receiver::replace_self_receiver;
 use crate::derive_component::replace_self_type::{
     iter_parse_and_replace_self_type, parse_and_replace_self_type,
@@ -16,11 +18,13 @@ use crate::derive_component::replace_self_type::{
 /// 1. Adding a context type parameter
 /// 2. Converting self-type references to context type references
 /// 3. Moving supertrait bounds to where clauses on the context type
+/// 4. Adding `IsProviderFor` as the supertrait, linking the provider trait to its component
 ///
 /// # Arguments
 /// * `consumer_trait` - The original consumer trait to transform
 /// * `provider_name` - Name for the generated provider trait
 /// * `context_type` - Name of the context type parameter
+/// * `component_type` - The component name type that the provider trait belongs to
 ///
 /// # Returns
 /// * `syn::Result<ItemTrait>` - The generated provider trait
@@ -36,7 +40,7 @@ use crate::derive_component::replace_self_type::{
 ///
 /// To provider trait:
 /// ```ignore
-/// trait MyComponentProvider<Context>
+/// trait MyComponentProvider<Context>: IsProviderFor<MyComponent, Context, ()>
 /// where
 ///     Context: SuperTrait
 /// {
@@ -56,17 +60,20 @@ pub fn derive_provider_trait(
     consumer_trait: &ItemTrait,
     provider_name: &Ident,
     context_type: &Ident,
+    component_type: &Type,
 ) -> syn::Result<ItemTrait> {
     let mut provider_trait = consumer_trait.clone();
 
     provider_trait.ident = provider_name.clone();
 
-    // Add generic parameter `Context` to the front of generics
+    // Add generic parameter `Context` to the front of generics, right after any lifetime parameters
     {
+        let lifetime_count = provider_trait.generics.lifetimes().count();
+
         provider_trait
             .generics
             .params
-            .insert(0, parse_quote!(#context_type));
+            .insert(lifetime_count, parse_quote!(#context_type));
     }
 
     let local_assoc_types: Vec<Ident> = provider_trait
@@ -91,6 +98,13 @@ pub fn derive_provider_trait(
 
         provider_trait.supertraits = Punctuated::default();
 
+        let params = derive_is_provider_params(&consumer_trait.generics);
+
+        provider_trait.colon_token = Some(Colon::default());
+        provider_trait.supertraits.push(parse_quote! {
+            IsProviderFor< #component_type, #context_type, #params >
+        });
+
         if !context_constraints.is_empty() {
             if let Some(where_clause) = &mut provider_trait.generics.where_clause {
                 let mut predicates = iter_parse_and_replace_self_type(
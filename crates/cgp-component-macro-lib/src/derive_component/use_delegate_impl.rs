@@ -0,0 +1,165 @@
+/// Implementation generation for `UseDelegate`-based providers.
+///
+/// This module handles the generation of a provider implementation for
+/// `UseDelegate<Components>`, which dispatches to different providers based on
+/// the generic parameters of the component.
+use proc_macro2::Span;
+use syn::punctuated::Punctuated;
+use syn::token::{Brace, For, Impl, Plus};
+use syn::{parse_quote, GenericParam, Ident, ItemImpl, ItemTrait, Path, Type, TypeParamBound};
+
+use crate::derive_component::is_provider_params::derive_is_provider_params;
+use crate::derive_component::provider_impl::{
+    derive_delegated_provider_items, derive_provider_generic_args,
+};
+
+/// Derives an implementation of a provider trait for `UseDelegate<Components>`,
+/// together with the corresponding `IsProviderFor` implementation.
+///
+/// The generated implementation looks up the provider to use from `Components`
+/// through `DelegateComponent`, using the given dispatch type as the key.
+///
+/// # Arguments
+/// * `provider_trait` - The trait defining the provider interface
+/// * `context_type` - Name of the context type parameter
+/// * `component_type` - The component name type that the provider trait belongs to
+/// * `delegate_key` - The type used as the key to look up the delegated provider,
+///   typically one of the generic parameters of the provider trait
+///
+/// # Returns
+/// * `(ItemImpl, ItemImpl)` - The provider implementation and the `IsProviderFor`
+///   implementation
+///
+/// # Generated Code Example
+/// ```ignore
+/// impl<Context, Tag, Components> ProvideType<Context, Tag> for UseDelegate<Components>
+/// where
+///     Components: DelegateComponent<Tag>,
+///     Components::Delegate: ProvideType<Context, Tag>,
+/// {
+///     type Type = <Components::Delegate as ProvideType<Context, Tag>>::Type;
+/// }
+///
+/// impl<Context, Tag, Components> IsProviderFor<TypeComponent, Context, Tag>
+///     for UseDelegate<Components>
+/// where
+///     Components: DelegateComponent<Tag>,
+///     Components::Delegate: IsProviderFor<TypeComponent, Context, Tag>,
+/// {
+/// }
+/// ```
+pub fn derive_use_delegate_impl(
+    provider_trait: &ItemTrait,
+    context_type: &Ident,
+    component_type: &Type,
+    delegate_key: &Type,
+) -> (ItemImpl, ItemImpl) {
+    let provider_name = &provider_trait.ident;
+
+    let components_type = Ident::new("Components", Span::call_site());
+
+    let provider_generic_args = derive_provider_generic_args(provider_trait);
+
+    let base_generics = {
+        let mut base_generics = provider_trait.generics.clone();
+
+        base_generics.params.push(parse_quote!(#components_type));
+
+        base_generics
+    };
+
+    let is_provider_params = {
+        let mut generics = provider_trait.generics.clone();
+
+        generics.params = generics
+            .params
+            .into_iter()
+            .filter(|param| !matches!(param, GenericParam::Type(ty) if &ty.ident == context_type))
+            .collect();
+
+        derive_is_provider_params(&generics)
+    };
+
+    let is_provider_constraint: Punctuated<TypeParamBound, Plus> = parse_quote! {
+        IsProviderFor< #component_type, #context_type, #is_provider_params >
+    };
+
+    let impl_generics = {
+        let mut impl_generics = base_generics.clone();
+
+        {
+            let delegate_constraint: Punctuated<TypeParamBound, Plus> = parse_quote! {
+                DelegateComponent< #delegate_key >
+            };
+
+            let provider_constraint: Punctuated<TypeParamBound, Plus> = parse_quote! {
+                #provider_name < #provider_generic_args >
+            };
+
+            if let Some(where_clause) = &mut impl_generics.where_clause {
+                where_clause.predicates.push(parse_quote! {
+                    #components_type : #delegate_constraint
+                });
+
+                where_clause.predicates.push(parse_quote! {
+                    #components_type :: Delegate : #provider_constraint
+                });
+            } else {
+                impl_generics.where_clause = Some(parse_quote! {
+                    where
+                        #components_type : #delegate_constraint,
+                        #components_type :: Delegate : #provider_constraint
+                });
+            }
+        }
+
+        impl_generics
+    };
+
+    let impl_items = derive_delegated_provider_items(
+        provider_trait,
+        &provider_generic_args,
+        &parse_quote!(#components_type :: Delegate),
+    );
+
+    let trait_path: Path = parse_quote!( #provider_name < #provider_generic_args > );
+
+    let provider_impl = ItemImpl {
+        attrs: provider_trait.attrs.clone(),
+        defaultness: None,
+        unsafety: provider_trait.unsafety,
+        impl_token: Impl::default(),
+        generics: impl_generics,
+        trait_: Some((None, trait_path, For::default())),
+        self_ty: Box::new(parse_quote!(UseDelegate< #components_type >)),
+        brace_token: Brace::default(),
+        items: impl_items,
+    };
+
+    let is_provider_generics = {
+        let mut is_provider_generics = base_generics;
+
+        let where_clause = is_provider_generics.make_where_clause();
+
+        where_clause.predicates.push(parse_quote! {
+            #components_type : DelegateComponent< #delegate_key >
+        });
+
+        where_clause.predicates.push(parse_quote! {
+            #components_type :: Delegate : #is_provider_constraint
+        });
+
+        is_provider_generics
+    };
+
+    let (impl_generics, _, where_clause) = is_provider_generics.split_for_impl();
+
+    let is_provider_impl = parse_quote! {
+        impl #impl_generics #is_provider_constraint for UseDelegate< #components_type >
+        #where_clause
+        {
+        }
+    };
+
+    (provider_impl, is_provider_impl)
+}
@@ -0,0 +1,114 @@
+/// Core functionality for the `#[cgp_auto_getter]` attribute macro.
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{parse_quote, Error, Ident, ImplItemFn, ItemImpl, ItemTrait, Type};
+
+use crate::derive_component::provider_impl::derive_provider_generic_args;
+use crate::derive_getter::getter_field::parse_getter_fields;
+
+/// Derives a blanket implementation of a getter trait for all contexts that
+/// contain the fields through `HasField`, without defining a component.
+///
+/// Each getter method gets the field tagged with the symbol of the method name.
+///
+/// # Arguments
+/// * `attr` - Attribute tokens, which must be empty
+/// * `item` - The input trait definition tokens, containing only getter methods
+///
+/// # Returns
+/// * `syn::Result<TokenStream>` - The trait definition followed by the blanket
+///   implementation, or a spanned error if the trait contains items that are not
+///   getter methods
+///
+/// # Generated Code Example
+/// For the trait:
+/// ```ignore
+/// pub trait HasName {
+///     fn name(&self) -> &str;
+/// }
+/// ```
+///
+/// This generates:
+/// ```ignore
+/// impl<__Context> HasName for __Context
+/// where
+///     __Context: HasField<symbol!("name"), Value: ::core::convert::AsRef<str>>,
+/// {
+///     fn name(&self) -> &str {
+///         ::core::convert::AsRef::<str>::as_ref(
+///             self.get_field(::core::marker::PhantomData::<symbol!("name")>)
+///         )
+///     }
+/// }
+/// ```
+pub fn derive_auto_getter(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    if !attr.is_empty() {
+        return Err(Error::new_spanned(
+            attr,
+            "#[cgp_auto_getter] does not accept any argument",
+        ));
+    }
+
+    let getter_trait: ItemTrait = syn::parse2(item)?;
+
+    let auto_getter_impl = derive_auto_getter_impl(&getter_trait)?;
+
+    let mut output = getter_trait.to_token_stream();
+
+    output.extend(auto_getter_impl.to_token_stream());
+
+    Ok(output)
+}
+
+fn derive_auto_getter_impl(getter_trait: &ItemTrait) -> syn::Result<ItemImpl> {
+    let fields = parse_getter_fields(getter_trait)?;
+
+    let trait_name = &getter_trait.ident;
+    let trait_generic_args = derive_provider_generic_args(getter_trait);
+
+    let context_ident = Ident::new("__Context", Span::call_site());
+    let context_type: Type = parse_quote!(#context_ident);
+
+    let mut generics = getter_trait.generics.clone();
+
+    generics.params.insert(0, parse_quote!(#context_ident));
+
+    {
+        let where_clause = generics.make_where_clause();
+
+        if !getter_trait.supertraits.is_empty() {
+            let supertraits = &getter_trait.supertraits;
+
+            where_clause
+                .predicates
+                .push(parse_quote!( #context_ident : #supertraits ));
+        }
+
+        for field in fields.iter() {
+            where_clause
+                .predicates
+                .push(field.field_constraint(&context_type, &field.field_symbol()));
+        }
+    }
+
+    let methods = fields.iter().map(|field| -> ImplItemFn {
+        let sig = &field.method.sig;
+        let field_expr = field.field_expr(&field.field_symbol());
+
+        parse_quote! {
+            #sig {
+                #field_expr
+            }
+        }
+    });
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(parse_quote! {
+        impl #impl_generics #trait_name < #trait_generic_args > for #context_ident
+        #where_clause
+        {
+            #( #methods )*
+        }
+    })
+}
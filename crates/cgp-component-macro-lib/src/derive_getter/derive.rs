@@ -0,0 +1,94 @@
+/// Core functionality for the `#[cgp_getter]` attribute macro.
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::ItemTrait;
+
+use crate::derive_component::component_name::derive_component_type;
+use crate::derive_component::component_spec::ComponentSpec;
+use crate::derive_component::default_method::split_default_methods;
+use crate::derive_component::derive::derive_component;
+use crate::derive_component::provider_trait::derive_provider_trait;
+use crate::derive_getter::getter_field::parse_getter_fields;
+use crate::derive_getter::use_context_impl::derive_use_context_impl;
+use crate::derive_getter::use_field_impl::derive_use_field_impl;
+use crate::derive_getter::with_provider_impl::derive_with_provider_impl;
+use crate::derive_provider::is_provider_impl::derive_is_provider_impl;
+
+/// Derives a getter component from a trait definition.
+///
+/// Besides everything generated by [`derive_component`], this also implements
+/// the provider trait for:
+/// - `UseContext`, which gets each field from the context through `HasField`,
+///   using the symbol of the method name as the field tag
+/// - `UseField<Tag>`, which gets the field with the given `Tag` through `HasField`.
+///   This is only generated if all getter methods access the same field, such as
+///   a single getter method, or a getter method with its `_mut` counterpart.
+/// - `WithProvider<Provider>`, which gets the field through the `FieldGetter`
+///   implementation of `Provider`, using the component name as the tag. This is
+///   generated under the same condition as `UseField<Tag>`.
+///
+/// Mutable getter methods, which take `&mut self`, get the field through
+/// `HasFieldMut` or `MutFieldGetter` instead, with the `_mut` suffix removed
+/// from the field name.
+///
+/// # Arguments
+/// * `attr` - Attribute tokens containing the component specification
+/// * `item` - The input trait definition tokens, containing only getter methods
+///
+/// # Returns
+/// * `syn::Result<TokenStream>` - Generated code containing the component and the
+///   getter provider implementations, or a spanned error if the trait contains
+///   items that are not getter methods
+pub fn derive_getter(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let spec: ComponentSpec = syn::parse2(attr.clone())?;
+
+    let raw_consumer_trait: ItemTrait = syn::parse2(item.clone())?;
+
+    let (_, delegated_trait) = split_default_methods(&raw_consumer_trait)?;
+
+    let context_type = &spec.context_type;
+
+    let component_type = derive_component_type(&spec.component_name, &spec.component_params);
+
+    let provider_trait = derive_provider_trait(
+        &delegated_trait,
+        &spec.provider_name,
+        context_type,
+        &component_type,
+    )?;
+
+    let fields = parse_getter_fields(&provider_trait)?;
+
+    let mut output = derive_component(attr, item)?;
+
+    let use_context_impl = derive_use_context_impl(&provider_trait, context_type, &fields);
+
+    let use_context_is_provider_impl = derive_is_provider_impl(&use_context_impl, &component_type)?;
+
+    output.extend(use_context_impl.to_token_stream());
+    output.extend(use_context_is_provider_impl.to_token_stream());
+
+    let access_single_field = fields
+        .iter()
+        .all(|field| field.field_name == fields[0].field_name);
+
+    if !fields.is_empty() && access_single_field {
+        let use_field_impl = derive_use_field_impl(&provider_trait, context_type, &fields);
+
+        let use_field_is_provider_impl = derive_is_provider_impl(&use_field_impl, &component_type)?;
+
+        output.extend(use_field_impl.to_token_stream());
+        output.extend(use_field_is_provider_impl.to_token_stream());
+
+        let with_provider_impl =
+            derive_with_provider_impl(&provider_trait, context_type, &component_type, &fields);
+
+        let with_provider_is_provider_impl =
+            derive_is_provider_impl(&with_provider_impl, &component_type)?;
+
+        output.extend(with_provider_impl.to_token_stream());
+        output.extend(with_provider_is_provider_impl.to_token_stream());
+    }
+
+    Ok(output)
+}
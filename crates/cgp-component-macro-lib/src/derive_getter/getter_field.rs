@@ -0,0 +1,306 @@
+/// Parsing of the getter methods of a getter component.
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse_quote, Error, FnArg, GenericArgument, Ident, ItemTrait, Pat, Path, PathArguments,
+    ReturnType, TraitItem, TraitItemFn, Type, WherePredicate,
+};
+
+/// A getter method of a getter trait.
+pub struct GetterField {
+    /// The getter method, which is used as the signature of the implementation
+    pub method: TraitItemFn,
+    /// The name of the method, which is also the name of the field
+    pub field_name: Ident,
+    /// The name of the context argument of the method, which is `self` for
+    /// methods in a consumer trait
+    pub context_arg: Ident,
+    /// The kind of reference returned by the getter method
+    pub kind: GetterFieldKind,
+    /// Whether the getter method takes and returns mutable references, in which
+    /// case the field is accessed through `HasFieldMut`
+    pub mutable: bool,
+}
+
+/// The kinds of references that can be returned by a getter method, which
+/// determine the constraint on the field value. For mutable getters, the
+/// references are mutable, and `AsMut` is used in place of `AsRef`.
+pub enum GetterFieldKind {
+    /// `&T`, which is returned from a field with value `T`
+    Ref(Type),
+    /// `&str`, which is returned from a field with a value that implements `AsRef<str>`,
+    /// such as `String`
+    Str,
+    /// `&[T]`, which is returned from a field with a value that implements `AsRef<[T]>`,
+    /// such as `Vec<T>`
+    Slice(Type),
+    /// `Option<&T>`, which is returned from a field with value `Option<T>`
+    OptionRef(Type),
+}
+
+/// Parses the getter methods from a provider trait or a consumer trait.
+///
+/// Every item of the trait must be a method without generic parameters, which
+/// takes the context by reference and returns one of the supported references
+/// to the field value:
+///
+/// * `fn name(&self) -> &T`
+/// * `fn name(&self) -> &str`
+/// * `fn name(&self) -> &[T]`
+/// * `fn name(&self) -> Option<&T>`
+///
+/// Mutable getters take `&mut self` and return the mutable counterparts of the
+/// references above, such as `fn name_mut(&mut self) -> &mut T`. The `_mut`
+/// suffix is removed from the method name to get the field name.
+///
+/// # Errors
+/// Returns an error pointing at the first item that is not a getter method.
+pub fn parse_getter_fields(getter_trait: &ItemTrait) -> syn::Result<Vec<GetterField>> {
+    getter_trait
+        .items
+        .iter()
+        .map(|item| match item {
+            TraitItem::Fn(method) => parse_getter_field(method),
+            _ => Err(Error::new_spanned(
+                item,
+                "getter traits can only contain getter methods, such as `fn name(&self) -> &String`",
+            )),
+        })
+        .collect()
+}
+
+fn parse_getter_field(method: &TraitItemFn) -> syn::Result<GetterField> {
+    let sig = &method.sig;
+
+    let invalid_getter = || {
+        Error::new_spanned(
+            sig,
+            "expect getter method to take `&self` as the only argument, and to return `&T`, `&str`, `&[T]` or `Option<&T>`, such as `fn name(&self) -> &String`, or the mutable counterparts with `&mut self`",
+        )
+    };
+
+    if !sig.generics.params.is_empty() || sig.inputs.len() != 1 {
+        return Err(invalid_getter());
+    }
+
+    let (context_arg, mutable) = match sig.inputs.first() {
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() => (
+            Ident::new("self", Span::call_site()),
+            receiver.mutability.is_some(),
+        ),
+        Some(FnArg::Typed(arg)) => match (arg.pat.as_ref(), arg.ty.as_ref()) {
+            (Pat::Ident(pat), Type::Reference(arg_type)) => {
+                (pat.ident.clone(), arg_type.mutability.is_some())
+            }
+            _ => return Err(invalid_getter()),
+        },
+        _ => return Err(invalid_getter()),
+    };
+
+    let kind = match &sig.output {
+        ReturnType::Type(_, return_type) => {
+            parse_getter_field_kind(return_type, mutable).ok_or_else(invalid_getter)?
+        }
+        ReturnType::Default => return Err(invalid_getter()),
+    };
+
+    let field_name = if mutable {
+        let method_name = sig.ident.to_string();
+
+        match method_name.strip_suffix("_mut") {
+            Some(field_name) if !field_name.is_empty() => Ident::new(field_name, sig.ident.span()),
+            _ => sig.ident.clone(),
+        }
+    } else {
+        sig.ident.clone()
+    };
+
+    Ok(GetterField {
+        method: method.clone(),
+        field_name,
+        context_arg,
+        kind,
+        mutable,
+    })
+}
+
+/// Parses the kind of the returned reference, which must have the same
+/// mutability as the context argument.
+fn parse_getter_field_kind(return_type: &Type, mutable: bool) -> Option<GetterFieldKind> {
+    match return_type {
+        Type::Reference(return_type) if return_type.mutability.is_some() == mutable => {
+            match return_type.elem.as_ref() {
+                Type::Path(type_path)
+                    if type_path.qself.is_none() && type_path.path.is_ident("str") =>
+                {
+                    Some(GetterFieldKind::Str)
+                }
+                Type::Slice(slice) => Some(GetterFieldKind::Slice(slice.elem.as_ref().clone())),
+                field_type => Some(GetterFieldKind::Ref(field_type.clone())),
+            }
+        }
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let segment = type_path.path.segments.last()?;
+
+            if segment.ident != "Option" {
+                return None;
+            }
+
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+
+            match args.args.first() {
+                Some(GenericArgument::Type(Type::Reference(arg_type)))
+                    if args.args.len() == 1 && arg_type.mutability.is_some() == mutable =>
+                {
+                    Some(GetterFieldKind::OptionRef(arg_type.elem.as_ref().clone()))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+impl GetterField {
+    /// The constraint on the context for getting the field with the given tag.
+    pub fn field_constraint(&self, context_type: &Type, field_tag: &Type) -> WherePredicate {
+        let has_field: Path = if self.mutable {
+            parse_quote!(HasFieldMut)
+        } else {
+            parse_quote!(HasField)
+        };
+
+        let value_constraint = self.value_constraint();
+
+        parse_quote! {
+            #context_type : #has_field < #field_tag, #value_constraint >
+        }
+    }
+
+    /// The constraint on a field getter provider for getting the field of the
+    /// context with the given tag, through `FieldGetter`, or `MutFieldGetter`
+    /// for mutable getters.
+    pub fn field_getter_constraint(
+        &self,
+        provider_type: &Type,
+        context_type: &Type,
+        field_tag: &Type,
+    ) -> WherePredicate {
+        let field_getter: Path = if self.mutable {
+            parse_quote!(MutFieldGetter)
+        } else {
+            parse_quote!(FieldGetter)
+        };
+
+        let value_constraint = self.value_constraint();
+
+        parse_quote! {
+            #provider_type : #field_getter < #context_type, #field_tag, #value_constraint >
+        }
+    }
+
+    /// The constraint on the `Value` type of the field, which depends on the
+    /// kind of reference returned by the getter method.
+    fn value_constraint(&self) -> TokenStream {
+        let as_ref = if self.mutable {
+            quote!(::core::convert::AsMut)
+        } else {
+            quote!(::core::convert::AsRef)
+        };
+
+        match &self.kind {
+            GetterFieldKind::Ref(field_type) => quote!(Value = #field_type),
+            GetterFieldKind::Str => quote!(Value: #as_ref <str>),
+            GetterFieldKind::Slice(elem_type) => quote!(Value: #as_ref <[ #elem_type ]>),
+            GetterFieldKind::OptionRef(field_type) => {
+                quote!(Value = ::core::option::Option< #field_type >)
+            }
+        }
+    }
+
+    /// Whether the field value is only constrained by a bound. The value is then
+    /// an associated type projection, which only outlives the returned reference
+    /// if the generic parameters of the projection outlive it, such as the tag.
+    pub fn has_projected_value(&self) -> bool {
+        matches!(self.kind, GetterFieldKind::Str | GetterFieldKind::Slice(_))
+    }
+
+    /// The expression for getting the field with the given tag from the context argument.
+    pub fn field_expr(&self, field_tag: &Type) -> TokenStream {
+        let context_arg = &self.context_arg;
+
+        let get_field = if self.mutable {
+            quote!(get_field_mut)
+        } else {
+            quote!(get_field)
+        };
+
+        self.convert_field_expr(quote! {
+            #context_arg . #get_field (::core::marker::PhantomData::< #field_tag >)
+        })
+    }
+
+    /// The expression for getting the field with the given tag from the context
+    /// argument through a field getter provider.
+    pub fn field_getter_expr(
+        &self,
+        provider_type: &Type,
+        context_type: &Type,
+        field_tag: &Type,
+    ) -> TokenStream {
+        let context_arg = &self.context_arg;
+
+        let (field_getter, get_field) = if self.mutable {
+            (quote!(MutFieldGetter), quote!(get_field_mut))
+        } else {
+            (quote!(FieldGetter), quote!(get_field))
+        };
+
+        self.convert_field_expr(quote! {
+            < #provider_type as #field_getter < #context_type, #field_tag > >:: #get_field (
+                #context_arg,
+                ::core::marker::PhantomData,
+            )
+        })
+    }
+
+    /// Converts the reference to the field value into the reference returned
+    /// by the getter method.
+    fn convert_field_expr(&self, field: TokenStream) -> TokenStream {
+        let (as_ref, get_as_ref, option_as_ref) = if self.mutable {
+            (
+                quote!(::core::convert::AsMut),
+                quote!(as_mut),
+                quote!(::core::option::Option::as_mut),
+            )
+        } else {
+            (
+                quote!(::core::convert::AsRef),
+                quote!(as_ref),
+                quote!(::core::option::Option::as_ref),
+            )
+        };
+
+        match &self.kind {
+            GetterFieldKind::Ref(_) => field,
+            GetterFieldKind::Str => quote! {
+                #as_ref ::<str>:: #get_as_ref ( #field )
+            },
+            GetterFieldKind::Slice(elem_type) => quote! {
+                #as_ref ::<[ #elem_type ]>:: #get_as_ref ( #field )
+            },
+            GetterFieldKind::OptionRef(_) => quote! {
+                #option_as_ref ( #field )
+            },
+        }
+    }
+
+    /// The symbol tag of the field, which is named after the getter method.
+    pub fn field_symbol(&self) -> Type {
+        let field_name = self.field_name.to_string();
+
+        parse_quote!(symbol!( #field_name ))
+    }
+}
@@ -0,0 +1,14 @@
+/// Module for deriving getter components.
+///
+/// This module extends the component derivation with provider implementations
+/// that implement getter methods through the `HasField` trait. It also derives
+/// blanket implementations of getter traits that do not need a component.
+pub mod auto_getter;
+pub mod derive;
+pub mod getter_field;
+pub mod use_context_impl;
+pub mod use_field_impl;
+pub mod with_provider_impl;
+
+pub use auto_getter::derive_auto_getter;
+pub use derive::derive_getter;
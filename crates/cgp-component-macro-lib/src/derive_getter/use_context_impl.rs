@@ -0,0 +1,59 @@
+/// Implementation generation of getter providers for `UseContext`.
+use syn::{parse_quote, Ident, ImplItemFn, ItemImpl, ItemTrait};
+
+use crate::derive_component::provider_impl::derive_provider_generic_args;
+use crate::derive_getter::getter_field::GetterField;
+
+/// Derives an implementation of the provider trait of a getter component for
+/// `UseContext`, which gets each field from the context through `HasField`,
+/// using the symbol of the method name as the field tag.
+///
+/// # Generated Code Example
+/// ```ignore
+/// impl<Context> NameGetter<Context> for UseContext
+/// where
+///     Context: HasField<symbol!("name"), Value = String>,
+/// {
+///     fn name(context: &Context) -> &String {
+///         context.get_field(::core::marker::PhantomData::<symbol!("name")>)
+///     }
+/// }
+/// ```
+pub fn derive_use_context_impl(
+    provider_trait: &ItemTrait,
+    context_type: &Ident,
+    fields: &[GetterField],
+) -> ItemImpl {
+    let provider_name = &provider_trait.ident;
+    let provider_generic_args = derive_provider_generic_args(provider_trait);
+
+    let mut generics = provider_trait.generics.clone();
+    let mut methods: Vec<ImplItemFn> = Vec::new();
+
+    for field in fields {
+        let field_symbol = field.field_symbol();
+        let field_expr = field.field_expr(&field_symbol);
+        let sig = &field.method.sig;
+
+        generics
+            .make_where_clause()
+            .predicates
+            .push(field.field_constraint(&parse_quote!(#context_type), &field_symbol));
+
+        methods.push(parse_quote! {
+            #sig {
+                #field_expr
+            }
+        });
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    parse_quote! {
+        impl #impl_generics #provider_name < #provider_generic_args > for UseContext
+        #where_clause
+        {
+            #( #methods )*
+        }
+    }
+}
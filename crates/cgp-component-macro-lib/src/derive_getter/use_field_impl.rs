@@ -0,0 +1,76 @@
+/// Implementation generation of getter providers for `UseField`.
+use proc_macro2::Span;
+use syn::{parse_quote, Ident, ImplItemFn, ItemImpl, ItemTrait, Type};
+
+use crate::derive_component::provider_impl::derive_provider_generic_args;
+use crate::derive_getter::getter_field::GetterField;
+
+/// Derives an implementation of the provider trait of a getter component for
+/// `UseField<Tag>`, which gets the field with the given `Tag` from the context
+/// through `HasField`, or `HasFieldMut` for mutable getters.
+///
+/// This is only meaningful if all getter methods access the same field, such
+/// as a single getter method, or a getter method with its `_mut` counterpart.
+///
+/// # Generated Code Example
+/// ```ignore
+/// impl<Context, __Tag> NameGetter<Context> for UseField<__Tag>
+/// where
+///     Context: HasField<__Tag, Value = String>,
+/// {
+///     fn name(context: &Context) -> &String {
+///         context.get_field(::core::marker::PhantomData::<__Tag>)
+///     }
+/// }
+/// ```
+pub fn derive_use_field_impl(
+    provider_trait: &ItemTrait,
+    context_type: &Ident,
+    fields: &[GetterField],
+) -> ItemImpl {
+    let provider_name = &provider_trait.ident;
+    let provider_generic_args = derive_provider_generic_args(provider_trait);
+
+    let tag_ident = Ident::new("__Tag", Span::call_site());
+    let tag_type: Type = parse_quote!(#tag_ident);
+
+    let mut generics = provider_trait.generics.clone();
+
+    generics.params.push(parse_quote!( #tag_ident ));
+
+    let mut methods: Vec<ImplItemFn> = Vec::new();
+
+    for field in fields {
+        let field_expr = field.field_expr(&tag_type);
+        let sig = &field.method.sig;
+
+        generics
+            .make_where_clause()
+            .predicates
+            .push(field.field_constraint(&parse_quote!(#context_type), &tag_type));
+
+        methods.push(parse_quote! {
+            #sig {
+                #field_expr
+            }
+        });
+    }
+
+    // The tag must outlive the references returned through projected values.
+    if fields.iter().any(GetterField::has_projected_value) {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!( #tag_ident: 'static ));
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    parse_quote! {
+        impl #impl_generics #provider_name < #provider_generic_args > for UseField< #tag_ident >
+        #where_clause
+        {
+            #( #methods )*
+        }
+    }
+}
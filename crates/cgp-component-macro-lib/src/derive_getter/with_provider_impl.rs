@@ -0,0 +1,90 @@
+/// Implementation generation of getter providers for `WithProvider`.
+use syn::{parse_quote, Ident, ImplItemFn, ItemImpl, ItemTrait, Type};
+
+use crate::derive_component::provider_impl::derive_provider_generic_args;
+use crate::derive_getter::getter_field::GetterField;
+
+/// Derives an implementation of the provider trait of a getter component for
+/// `WithProvider<Provider>`, which gets the field from the context through
+/// `Provider`, which implements `FieldGetter` with the component name as the
+/// tag. Mutable getters get the field through `MutFieldGetter` instead.
+///
+/// This allows the field to be provided by `WithField<Tag>`, or by a custom
+/// field getter. As with `UseField`, this is only meaningful if all getter
+/// methods access the same field.
+///
+/// # Generated Code Example
+/// ```ignore
+/// impl<Context, __Provider> CounterGetter<Context> for WithProvider<__Provider>
+/// where
+///     __Provider: FieldGetter<Context, CounterGetterComponent, Value = u64>,
+///     __Provider: MutFieldGetter<Context, CounterGetterComponent, Value = u64>,
+/// {
+///     fn counter(context: &Context) -> &u64 {
+///         <__Provider as FieldGetter<Context, CounterGetterComponent>>::get_field(
+///             context,
+///             ::core::marker::PhantomData,
+///         )
+///     }
+///
+///     fn counter_mut(context: &mut Context) -> &mut u64 {
+///         <__Provider as MutFieldGetter<Context, CounterGetterComponent>>::get_field_mut(
+///             context,
+///             ::core::marker::PhantomData,
+///         )
+///     }
+/// }
+/// ```
+pub fn derive_with_provider_impl(
+    provider_trait: &ItemTrait,
+    context_type: &Ident,
+    component_type: &Type,
+    fields: &[GetterField],
+) -> ItemImpl {
+    let provider_name = &provider_trait.ident;
+    let provider_generic_args = derive_provider_generic_args(provider_trait);
+
+    let provider_ident = Ident::new("__Provider", context_type.span());
+    let provider_type: Type = parse_quote!(#provider_ident);
+    let context_type: Type = parse_quote!(#context_type);
+
+    let mut generics = provider_trait.generics.clone();
+
+    generics.params.push(parse_quote!( #provider_ident ));
+
+    let mut methods: Vec<ImplItemFn> = Vec::new();
+
+    for field in fields {
+        let field_expr = field.field_getter_expr(&provider_type, &context_type, component_type);
+        let sig = &field.method.sig;
+
+        generics
+            .make_where_clause()
+            .predicates
+            .push(field.field_getter_constraint(&provider_type, &context_type, component_type));
+
+        methods.push(parse_quote! {
+            #sig {
+                #field_expr
+            }
+        });
+    }
+
+    // The provider must outlive the references returned through projected values.
+    if fields.iter().any(GetterField::has_projected_value) {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!( #provider_ident: 'static ));
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    parse_quote! {
+        impl #impl_generics #provider_name < #provider_generic_args > for WithProvider< #provider_ident >
+        #where_clause
+        {
+            #( #methods )*
+        }
+    }
+}
@@ -0,0 +1,53 @@
+/// Core functionality for the `#[cgp_provider]` attribute macro.
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{parse_quote, Error, Ident, ItemImpl, Type};
+
+use crate::derive_provider::is_provider_impl::derive_is_provider_impl;
+
+/// Processes a provider trait implementation annotated with `#[cgp_provider]`.
+///
+/// # Arguments
+/// * `attr` - The component name type, e.g. `FooComponent`. If empty, the component
+///   name defaults to the provider trait name with a `Component` suffix.
+/// * `body` - The provider trait implementation
+///
+/// # Returns
+/// * `syn::Result<TokenStream>` - The original implementation, followed by the
+///   `IsProviderFor` implementation for the provider
+///
+/// # Errors
+/// Returns an error if the body is not a trait implementation, or if the
+/// implemented trait has no context type argument.
+pub fn derive_provider(attr: TokenStream, body: TokenStream) -> syn::Result<TokenStream> {
+    let provider_impl: ItemImpl = syn::parse2(body)?;
+
+    let component_type: Type = if attr.is_empty() {
+        let (_, trait_path, _) = provider_impl.trait_.as_ref().ok_or_else(|| {
+            Error::new_spanned(
+                &provider_impl.self_ty,
+                "#[cgp_provider] must be applied to a provider trait implementation",
+            )
+        })?;
+
+        let trait_ident = &trait_path
+            .segments
+            .last()
+            .ok_or_else(|| Error::new_spanned(trait_path, "expect provider trait path"))?
+            .ident;
+
+        let component_ident = Ident::new(&format!("{trait_ident}Component"), trait_ident.span());
+
+        parse_quote!(#component_ident)
+    } else {
+        syn::parse2(attr)?
+    };
+
+    let is_provider_impl = derive_is_provider_impl(&provider_impl, &component_type)?;
+
+    let mut output = provider_impl.to_token_stream();
+
+    output.extend(is_provider_impl.to_token_stream());
+
+    Ok(output)
+}
@@ -0,0 +1,178 @@
+/// Generation of `IsProviderFor` implementations for provider implementations.
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_quote, Error, GenericArgument, GenericParam, Ident, ItemImpl, PathArguments, Type,
+    WherePredicate,
+};
+
+use crate::derive_component::is_provider_params::{lifetime_param, pack_is_provider_params};
+
+/// Derives the `IsProviderFor` implementation for a provider trait implementation.
+///
+/// The first type argument of the provider trait is taken as the context type, and
+/// the remaining lifetime and type arguments are packed into the `Params` argument.
+/// The implementation has the same generic parameters and `where` clause as the
+/// provider trait implementation, except for const generic parameters that are
+/// only used in the provider trait arguments. Since const generic parameters are
+/// not included in `Params`, such parameters would be unconstrained in the
+/// `IsProviderFor` implementation, and are removed together with the `where`
+/// predicates that mention them.
+///
+/// # Arguments
+/// * `provider_impl` - The provider trait implementation
+/// * `component_type` - The component name type that the provider implements
+///
+/// # Returns
+/// * `syn::Result<ItemImpl>` - The generated `IsProviderFor` implementation
+///
+/// # Example
+/// For the implementation:
+/// ```ignore
+/// impl<'a, Context, Tag> FooProvider<'a, Context, Tag> for MyProvider
+/// where
+///     Context: HasBar,
+/// { ... }
+/// ```
+///
+/// This generates:
+/// ```ignore
+/// impl<'a, Context, Tag> IsProviderFor<FooComponent, Context, (&'a (), Tag)> for MyProvider
+/// where
+///     Context: HasBar,
+/// {
+/// }
+/// ```
+pub fn derive_is_provider_impl(
+    provider_impl: &ItemImpl,
+    component_type: &Type,
+) -> syn::Result<ItemImpl> {
+    let (_, trait_path, _) = provider_impl.trait_.as_ref().ok_or_else(|| {
+        Error::new_spanned(
+            &provider_impl.self_ty,
+            "#[cgp_provider] must be applied to a provider trait implementation",
+        )
+    })?;
+
+    let missing_context_error = || {
+        Error::new_spanned(
+            trait_path,
+            "expect the provider trait to have the context type as its first type argument",
+        )
+    };
+
+    let trait_segment = trait_path
+        .segments
+        .last()
+        .ok_or_else(missing_context_error)?;
+
+    let PathArguments::AngleBracketed(trait_args) = &trait_segment.arguments else {
+        return Err(missing_context_error());
+    };
+
+    let mut context_type: Option<&Type> = None;
+    let mut params: Vec<Type> = Vec::new();
+
+    for arg in trait_args.args.iter() {
+        match arg {
+            GenericArgument::Lifetime(lifetime) => {
+                params.push(lifetime_param(lifetime));
+            }
+            GenericArgument::Type(ty) if context_type.is_none() => {
+                context_type = Some(ty);
+            }
+            GenericArgument::Type(ty) => {
+                // Const generic parameters are passed as plain identifiers, and are
+                // not included in `Params`.
+                if !is_const_param(provider_impl, ty) {
+                    params.push(ty.clone());
+                }
+            }
+            GenericArgument::Const(_) => {}
+            _ => {
+                return Err(Error::new_spanned(
+                    arg,
+                    "unsupported generic argument in provider trait implementation",
+                ));
+            }
+        }
+    }
+
+    let context_type = context_type.ok_or_else(missing_context_error)?;
+
+    let params = pack_is_provider_params(params);
+
+    let self_ty = &provider_impl.self_ty;
+
+    let mut generics = provider_impl.generics.clone();
+
+    let constrained_tokens = {
+        let mut tokens = self_ty.to_token_stream();
+        tokens.extend(context_type.to_token_stream());
+        tokens.extend(params.to_token_stream());
+        tokens
+    };
+
+    let unconstrained_consts: Vec<Ident> = generics
+        .const_params()
+        .map(|param| param.ident.clone())
+        .filter(|ident| !mentions_ident(constrained_tokens.clone(), ident))
+        .collect();
+
+    if !unconstrained_consts.is_empty() {
+        generics.params = generics
+            .params
+            .into_iter()
+            .filter(|param| match param {
+                GenericParam::Const(param) => !unconstrained_consts.contains(&param.ident),
+                _ => true,
+            })
+            .collect();
+
+        if let Some(where_clause) = &mut generics.where_clause {
+            where_clause.predicates = where_clause
+                .predicates
+                .iter()
+                .filter(|predicate| {
+                    !unconstrained_consts
+                        .iter()
+                        .any(|ident| mentions_ident(predicate.to_token_stream(), ident))
+                })
+                .cloned()
+                .collect::<Punctuated<WherePredicate, _>>();
+        }
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(parse_quote! {
+        impl #impl_generics IsProviderFor< #component_type, #context_type, #params > for #self_ty
+        #where_clause
+        {
+        }
+    })
+}
+
+fn is_const_param(provider_impl: &ItemImpl, ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+
+    provider_impl
+        .generics
+        .params
+        .iter()
+        .any(|param| match param {
+            GenericParam::Const(const_param) => type_path.path.is_ident(&const_param.ident),
+            _ => false,
+        })
+}
+
+fn mentions_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(token) => &token == ident,
+        TokenTree::Group(group) => mentions_ident(group.stream(), ident),
+        _ => false,
+    })
+}
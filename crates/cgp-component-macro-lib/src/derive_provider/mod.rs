@@ -0,0 +1,29 @@
+/// Provider implementation support for the CGP framework.
+///
+/// This module implements the `#[cgp_provider]` attribute macro, which is applied
+/// to implementations of provider traits. Besides emitting the implementation as is,
+/// it implements `IsProviderFor` for the provider, linking it to the component that
+/// the provider trait belongs to.
+///
+/// # Module Structure
+///
+/// * [`derive`] - Core functionality for processing provider implementations
+/// * [`is_provider_impl`] - Generation of the `IsProviderFor` implementation
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[cgp_provider(FooComponent)]
+/// impl<Context> FooProvider<Context> for MyProvider {
+///     fn foo(_context: &Context) -> u64 {
+///         42
+///     }
+/// }
+///
+/// // Additionally generates:
+/// impl<Context> IsProviderFor<FooComponent, Context, ()> for MyProvider {}
+/// ```
+pub mod derive;
+pub mod is_provider_impl;
+
+pub use derive::derive_provider;
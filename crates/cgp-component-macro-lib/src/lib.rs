@@ -9,19 +9,28 @@
    It includes functionality for:
    - Delegating component implementations
    - Deriving component traits
+   - Deriving getter components and getter traits that are implemented through `HasField`
+   - Implementing provider traits
+   - Checking the wiring of components in a context
    - Pattern replacement utilities
    - Preset component definitions
 */
 
+pub mod check_components;
 pub mod delegate_components;
 pub mod derive_component;
+pub mod derive_getter;
+pub mod derive_provider;
 pub mod for_each_replace;
 pub mod preset;
 
 #[cfg(test)]
 mod tests;
 
+pub use crate::check_components::check_components;
 pub use crate::delegate_components::delegate_components;
 pub use crate::derive_component::derive_component;
+pub use crate::derive_getter::{derive_auto_getter, derive_getter};
+pub use crate::derive_provider::derive_provider;
 pub use crate::for_each_replace::{handle_for_each_replace, handle_replace};
 pub use crate::preset::define_preset;
@@ -3,10 +3,11 @@
 /// This module provides the AST structures necessary for parsing preset definitions
 /// in the CGP framework.
 use syn::parse::{Parse, ParseStream};
-use syn::token::Lt;
-use syn::{Generics, Ident};
+use syn::punctuated::Punctuated;
+use syn::token::{Colon, Comma, Lt};
+use syn::{braced, bracketed, Error, Generics, Ident, Token, Type};
 
-use crate::delegate_components::ast::DelegateEntriesAst;
+use crate::delegate_components::ast::{ComponentAst, DelegateEntriesAst, DelegateEntryAst};
 
 /// AST node representing a complete preset definition.
 ///
@@ -17,6 +18,11 @@ use crate::delegate_components::ast::DelegateEntriesAst;
 ///
 /// * `preset_ident` - The identifier for the preset being defined
 /// * `preset_generics` - Generic parameters associated with the preset
+/// * `parent_preset` - The parent preset that this preset extends, if any
+/// * `inherited_components` - The full list of components of the parent preset.
+///   This is only available after the parent's `with_*` macro has been expanded.
+/// * `override_components` - Components marked with `override`, which replace
+///   the corresponding entries of the parent preset
 /// * `delegate_entries` - Specifications for component delegations within the preset
 ///
 /// # Examples
@@ -25,24 +31,57 @@ use crate::delegate_components::ast::DelegateEntriesAst;
 /// MyPreset<T> {
 ///     [ComponentA, ComponentB]: Inner<T>
 /// }
+///
+/// MyExtendedPreset<T>: MyPreset<T> {
+///     override ComponentA: OtherInner<T>,
+///     ComponentC: Inner<T>,
+/// }
 /// ```
 pub struct DefinePresetAst {
     pub preset_ident: Ident,
     pub preset_generics: Generics,
+    pub parent_preset: Option<Type>,
+    pub inherited_components: Option<Punctuated<ComponentAst, Comma>>,
+    pub override_components: Vec<ComponentAst>,
     pub delegate_entries: DelegateEntriesAst,
 }
 
+/// AST node representing a single entry in a preset definition, which may be
+/// prefixed with `override`.
+pub struct PresetEntryAst {
+    pub override_token: Option<Token![override]>,
+    pub entry: DelegateEntryAst,
+}
+
 /// Parse implementation for preset definitions.
 ///
 /// Parses input in the format:
 /// ```text
-/// preset_name<generic_params>? { delegate_entries }
+/// preset_name<generic_params>? (: parent_preset)? { delegate_entries }
 /// ```
 ///
-/// The generic parameters are optional, and delegate entries follow the same
-/// format as in component delegation.
+/// The generic parameters and parent preset are optional, and delegate entries
+/// follow the same format as in component delegation, with an optional `override`
+/// prefix. When the parent preset has been expanded, the input is prefixed with
+/// `@inherit [ parent_components ]`.
 impl Parse for DefinePresetAst {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let inherited_components = if input.peek(Token![@]) {
+            let _: Token![@] = input.parse()?;
+
+            let keyword: Ident = input.parse()?;
+            if keyword != "inherit" {
+                return Err(Error::new(keyword.span(), "expect `@inherit`"));
+            }
+
+            let components_body;
+            bracketed!(components_body in input);
+
+            Some(components_body.parse_terminated(ComponentAst::parse, Comma)?)
+        } else {
+            None
+        };
+
         let preset_ident: Ident = input.parse()?;
 
         let preset_generics = if input.peek(Lt) {
@@ -51,12 +90,67 @@ impl Parse for DefinePresetAst {
             Default::default()
         };
 
-        let delegate_entries: DelegateEntriesAst = input.parse()?;
+        let parent_preset: Option<Type> = if input.peek(Colon) {
+            let _: Colon = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let preset_entries: Punctuated<PresetEntryAst, Comma> = {
+            let entries_body;
+            braced!(entries_body in input);
+            entries_body.parse_terminated(PresetEntryAst::parse, Comma)?
+        };
+
+        let mut override_components = Vec::new();
+        let mut entries = Punctuated::new();
+
+        for preset_entry in preset_entries {
+            if let Some(override_token) = &preset_entry.override_token {
+                if parent_preset.is_none() {
+                    return Err(Error::new(
+                        override_token.span,
+                        "`override` can only be used in a preset that extends a parent preset",
+                    ));
+                }
+
+                override_components.extend(preset_entry.entry.components.iter().cloned());
+            }
+
+            entries.push(preset_entry.entry);
+        }
 
         Ok(Self {
             preset_ident,
             preset_generics,
-            delegate_entries,
+            parent_preset,
+            inherited_components,
+            override_components,
+            delegate_entries: DelegateEntriesAst { entries },
+        })
+    }
+}
+
+/// Parse implementation for a single preset entry.
+///
+/// Parses input in the format:
+/// ```text
+/// override? [comp1, comp2, ...]: source_type
+/// ```
+impl Parse for PresetEntryAst {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let override_token = if input.peek(Token![override]) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let entry: DelegateEntryAst = input.parse()?;
+
+        Ok(Self {
+            override_token,
+            entry,
         })
     }
 }
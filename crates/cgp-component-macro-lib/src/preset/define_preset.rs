@@ -3,11 +3,15 @@
 /// This module provides the core functionality for defining presets, which are
 /// reusable component configurations that can be applied to different types.
 use proc_macro2::{Span, TokenStream};
-use quote::ToTokens;
-use syn::{parse_quote, Ident, ItemTrait};
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{parse_quote, Error, Ident, ItemTrait, Type};
 
+use crate::delegate_components::ast::{ComponentAst, DelegateEntriesAst, DelegateEntryAst};
 use crate::delegate_components::define_struct::define_struct;
 use crate::delegate_components::delegates_to::define_delegates_to_trait;
+use crate::delegate_components::duplicate::{check_duplicate_components, normalize_component};
 use crate::delegate_components::impl_delegate::impl_delegate_components;
 use crate::derive_component::snake_case::to_snake_case_str;
 use crate::preset::ast::DefinePresetAst;
@@ -46,13 +50,137 @@ use crate::preset::substitution_macro::define_substitution_macro;
 /// - A trait `IsMyPreset<Component>`
 /// - Implementation of component delegation
 /// - A `with_my_preset` macro for type substitution
+///
+/// A preset can also extend a parent preset, such as `MyExtendedPreset: MyPreset`.
+/// Since the components of the parent preset are only known to its `with_*`
+/// macro, the definition is first wrapped in a call to that macro, which passes
+/// back the parent components through `@inherit [ ... ]`. The components that
+/// are not specified again are then delegated to the parent preset.
 pub fn define_preset(body: TokenStream) -> syn::Result<TokenStream> {
-    let ast: DefinePresetAst = syn::parse2(body)?;
+    let ast: DefinePresetAst = syn::parse2(body.clone())?;
+
+    match (&ast.parent_preset, &ast.inherited_components) {
+        (Some(parent_preset), None) => expand_parent_preset(parent_preset, body),
+        (None, Some(_)) => Err(Error::new(
+            ast.preset_ident.span(),
+            "`@inherit` can only be used in a preset that extends a parent preset",
+        )),
+        _ => derive_preset(ast),
+    }
+}
+
+/// Wraps the preset definition in a call to the `with_*` macro of the parent
+/// preset, so that the parent components are made available via `@inherit`.
+fn expand_parent_preset(parent_preset: &Type, body: TokenStream) -> syn::Result<TokenStream> {
+    let mut macro_path = match parent_preset {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.clone(),
+        _ => {
+            return Err(Error::new_spanned(
+                parent_preset,
+                "the parent preset must be a path to a preset defined with `cgp_preset!`",
+            ))
+        }
+    };
+
+    if let Some(segment) = macro_path.segments.last_mut() {
+        segment.ident = Ident::new(
+            &format!("with_{}", to_snake_case_str(&segment.ident.to_string())),
+            segment.ident.span(),
+        );
+        segment.arguments = Default::default();
+    }
+
+    Ok(quote! {
+        #macro_path ! {
+            | __CgpParentComponents | {
+                cgp_preset! {
+                    @inherit __CgpParentComponents
+                    #body
+                }
+            }
+        }
+    })
+}
+
+/// Collects the delegate entries of a preset, including the entry that delegates
+/// the components inherited from the parent preset.
+fn derive_preset_entries(ast: DefinePresetAst) -> syn::Result<DelegateEntriesAst> {
+    let mut delegate_entries = ast.delegate_entries;
+
+    check_duplicate_components(&delegate_entries)?;
+
+    let (Some(parent_preset), Some(parent_components)) =
+        (ast.parent_preset, ast.inherited_components)
+    else {
+        return Ok(delegate_entries);
+    };
+
+    let is_parent_component = |component: &ComponentAst| {
+        parent_components
+            .iter()
+            .any(|parent| normalize_component(parent) == normalize_component(component))
+    };
+
+    let is_override = |component: &ComponentAst| {
+        ast.override_components
+            .iter()
+            .any(|over| normalize_component(over) == normalize_component(component))
+    };
+
+    for component in delegate_entries.all_components() {
+        let component_type = &component.component_type;
+
+        if is_override(&component) && !is_parent_component(&component) {
+            return Err(Error::new(
+                component_type.span(),
+                format!(
+                    "`{}` cannot be overridden, as it is not a component of the parent preset `{}`",
+                    component_type.to_token_stream(),
+                    parent_preset.to_token_stream(),
+                ),
+            ));
+        }
+
+        if !is_override(&component) && is_parent_component(&component) {
+            return Err(Error::new(
+                component_type.span(),
+                format!(
+                    "`{}` is already a component of the parent preset `{}`; use `override` to replace it",
+                    component_type.to_token_stream(),
+                    parent_preset.to_token_stream(),
+                ),
+            ));
+        }
+    }
+
+    let own_components = delegate_entries.all_components();
+
+    let inherited_components: Punctuated<ComponentAst, _> = parent_components
+        .into_iter()
+        .filter(|parent| {
+            !own_components
+                .iter()
+                .any(|component| normalize_component(component) == normalize_component(parent))
+        })
+        .collect();
+
+    if !inherited_components.is_empty() {
+        delegate_entries.entries.push(DelegateEntryAst {
+            components: inherited_components,
+            source: parent_preset,
+        });
+    }
+
+    Ok(delegate_entries)
+}
 
-    let preset_ident = &ast.preset_ident;
+fn derive_preset(ast: DefinePresetAst) -> syn::Result<TokenStream> {
+    let preset_ident = ast.preset_ident.clone();
+    let preset_generics = ast.preset_generics.clone();
+    let delegate_entries = derive_preset_entries(ast)?;
 
     let preset_type = {
-        let type_generics = ast.preset_generics.split_for_impl().1;
+        let type_generics = preset_generics.split_for_impl().1;
         parse_quote!( #preset_ident #type_generics )
     };
 
@@ -63,16 +191,16 @@ pub fn define_preset(body: TokenStream) -> syn::Result<TokenStream> {
     };
 
     let impl_delegate_items =
-        impl_delegate_components(&preset_type, &ast.preset_generics, &ast.delegate_entries);
+        impl_delegate_components(&preset_type, &preset_generics, &delegate_entries);
 
     let impl_is_reset_items = impl_components_is_preset(
         &preset_trait_name,
         &preset_type,
-        &ast.preset_generics,
-        &ast.delegate_entries,
+        &preset_generics,
+        &delegate_entries,
     );
 
-    let item_struct = define_struct(&ast.preset_ident, &ast.preset_generics);
+    let item_struct = define_struct(&preset_ident, &preset_generics);
 
     let mut output = TokenStream::new();
 
@@ -89,13 +217,13 @@ pub fn define_preset(body: TokenStream) -> syn::Result<TokenStream> {
     }
 
     {
-        let delegates_to_trait_name = format!("DelegatesTo{}", ast.preset_ident);
+        let delegates_to_trait_name = format!("DelegatesTo{}", preset_ident);
 
         let (delegates_to_trait, delegates_to_impl) = define_delegates_to_trait(
             &Ident::new(&delegates_to_trait_name, Span::call_site()),
             &preset_type,
-            &ast.preset_generics,
-            &ast.delegate_entries,
+            &preset_generics,
+            &delegate_entries,
         );
 
         output.extend(delegates_to_trait.to_token_stream());
@@ -104,11 +232,11 @@ pub fn define_preset(body: TokenStream) -> syn::Result<TokenStream> {
 
     {
         let with_components_macro_name =
-            format!("with_{}", to_snake_case_str(&ast.preset_ident.to_string()));
+            format!("with_{}", to_snake_case_str(&preset_ident.to_string()));
 
         let with_components_macro = define_substitution_macro(
             &Ident::new(&with_components_macro_name, Span::call_site()),
-            &ast.delegate_entries.all_components().to_token_stream(),
+            &delegate_entries.all_components().to_token_stream(),
         );
 
         output.extend(with_components_macro);
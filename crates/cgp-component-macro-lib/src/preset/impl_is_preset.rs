@@ -72,12 +72,15 @@ pub fn impl_component_is_preset(
     // component name's generic.
     // let generics = merge_generics(preset_generics, &component.component_generics);
 
+    // The where clause of the component is not included, as it may refer to the
+    // preset generics.
     let mut generics = component.component_generics.clone();
-    generics.params.push(parse_quote!(T));
+    generics.where_clause = None;
+    generics.params.push(parse_quote!(__T));
 
     let impl_generics = generics.split_for_impl().0;
 
     parse_quote! {
-        impl #impl_generics #trait_name < #component_type > for T {}
+        impl #impl_generics #trait_name < #component_type > for __T {}
     }
 }
@@ -0,0 +1,36 @@
+use quote::quote;
+
+use crate::check_components;
+use crate::tests::helper::equal::equal_token_stream;
+
+#[test]
+fn test_basic_check_components() {
+    let derived = check_components(quote! {
+        <'a, FooParam> FooContext<'a, FooParam> {
+            BarComponentA,
+            BarComponentB<'a, FooParam>,
+            BarComponentC: (FooParam, u64),
+        }
+    })
+    .unwrap();
+
+    let expected = quote! {
+        const _: () = {
+            trait __CheckComponents<Component, Params>: CanUseComponent<Component, Params> {}
+
+            impl<'a, FooParam> __CheckComponents<BarComponentA, ()> for FooContext<'a, FooParam> {}
+
+            impl<'a, FooParam> __CheckComponents<BarComponentB<'a, FooParam>, (&'a (), FooParam)>
+                for FooContext<'a, FooParam>
+            {
+            }
+
+            impl<'a, FooParam> __CheckComponents<BarComponentC, (FooParam, u64)>
+                for FooContext<'a, FooParam>
+            {
+            }
+        };
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
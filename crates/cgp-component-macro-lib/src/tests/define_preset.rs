@@ -33,11 +33,11 @@ fn test_basic_define_preset() {
             type Delegate = BazBComponents;
         }
 
-        impl<T> IsFooPreset<BarAComponent> for T {}
+        impl<__T> IsFooPreset<BarAComponent> for __T {}
 
-        impl<T> IsFooPreset<BarBComponent> for T {}
+        impl<__T> IsFooPreset<BarBComponent> for __T {}
 
-        impl<T> IsFooPreset<BarCComponent> for T {}
+        impl<__T> IsFooPreset<BarCComponent> for __T {}
 
         pub trait DelegatesToFooPreset: DelegateComponent<
                 BarAComponent,
@@ -133,13 +133,13 @@ fn test_define_preset_containing_generics() {
             type Delegate = BazComponentsB;
         }
 
-        impl<T> IsFooPreset<BarComponentA> for T {}
-        impl<T> IsFooPreset<BarComponentB<'a>> for T {}
-        impl<T> IsFooPreset<BarComponentC<FooParamB>> for T {}
+        impl<__T> IsFooPreset<BarComponentA> for __T {}
+        impl<__T> IsFooPreset<BarComponentB<'a>> for __T {}
+        impl<__T> IsFooPreset<BarComponentC<FooParamB>> for __T {}
 
-        impl<BarParamA, T> IsFooPreset<BarComponentD<BarParamA, FooParamA>> for T {}
+        impl<BarParamA, __T> IsFooPreset<BarComponentD<BarParamA, FooParamA>> for __T {}
 
-        impl<'b, BarParamB: BarConstraint, T> IsFooPreset<BarComponentE<'b, BarParamB, FooParamB>> for T {}
+        impl<'b, BarParamB: BarConstraint, __T> IsFooPreset<BarComponentE<'b, BarParamB, FooParamB>> for __T {}
 
         pub trait DelegatesToFooPreset<
             'a,
@@ -154,12 +154,6 @@ fn test_define_preset_containing_generics() {
             > + DelegateComponent<
                 BarComponentC<FooParamB>,
                 Delegate = FooPreset<'a, FooParamA, FooParamB>,
-            > + DelegateComponent<
-                BarComponentD<BarParamA, FooParamA>,
-                Delegate = FooPreset<'a, FooParamA, FooParamB>,
-            > + DelegateComponent<
-                BarComponentE<'b, BarParamB, FooParamB>,
-                Delegate = FooPreset<'a, FooParamA, FooParamB>,
             > {}
 
         impl<
@@ -180,14 +174,6 @@ fn test_define_preset_containing_generics() {
                 + DelegateComponent<
                     BarComponentC<FooParamB>,
                     Delegate = FooPreset<'a, FooParamA, FooParamB>,
-                >
-                + DelegateComponent<
-                    BarComponentD<BarParamA, FooParamA>,
-                    Delegate = FooPreset<'a, FooParamA, FooParamB>,
-                >
-                + DelegateComponent<
-                    BarComponentE<'b, BarParamB, FooParamB>,
-                    Delegate = FooPreset<'a, FooParamA, FooParamB>,
                 >,
         {}
 
@@ -212,3 +198,114 @@ fn test_define_preset_containing_generics() {
 
     assert!(equal_token_stream(&derived, &expected));
 }
+
+#[test]
+fn test_define_preset_with_parent_expands_parent_macro() {
+    let derived = define_preset(quote! {
+        FooPreset: base::BasePreset {
+            override BarAComponent: BazAComponents,
+        }
+    })
+    .unwrap();
+
+    let expected = quote! {
+        base::with_base_preset! {
+            | __CgpParentComponents | {
+                cgp_preset! {
+                    @inherit __CgpParentComponents
+                    FooPreset: base::BasePreset {
+                        override BarAComponent: BazAComponents,
+                    }
+                }
+            }
+        }
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_define_preset_inheriting_parent_components() {
+    let derived = define_preset(quote! {
+        @inherit [ BarAComponent, BarBComponent ]
+        FooPreset: BasePreset {
+            override BarAComponent: BazAComponents,
+            BarCComponent: BazBComponents,
+        }
+    })
+    .unwrap();
+
+    let expected = quote! {
+        pub struct FooPreset;
+
+        pub trait IsFooPreset<Component> {}
+
+        impl DelegateComponent<BarAComponent> for FooPreset {
+            type Delegate = BazAComponents;
+        }
+
+        impl DelegateComponent<BarCComponent> for FooPreset {
+            type Delegate = BazBComponents;
+        }
+
+        impl DelegateComponent<BarBComponent> for FooPreset {
+            type Delegate = BasePreset;
+        }
+
+        impl<__T> IsFooPreset<BarAComponent> for __T {}
+
+        impl<__T> IsFooPreset<BarCComponent> for __T {}
+
+        impl<__T> IsFooPreset<BarBComponent> for __T {}
+
+        pub trait DelegatesToFooPreset: DelegateComponent<
+                BarAComponent,
+                Delegate = FooPreset,
+            > + DelegateComponent<
+                BarCComponent,
+                Delegate = FooPreset,
+            > + DelegateComponent<BarBComponent, Delegate = FooPreset> {}
+
+        impl<Components> DelegatesToFooPreset for Components
+        where
+            Components: DelegateComponent<BarAComponent, Delegate = FooPreset>
+                + DelegateComponent<BarCComponent, Delegate = FooPreset>
+                + DelegateComponent<BarBComponent, Delegate = FooPreset>,
+        {}
+
+        #[macro_export]
+        macro_rules! with_foo_preset {
+            ($($body:tt)*) => {
+                replace_with! {
+                    [ BarAComponent, BarCComponent, BarBComponent ],
+                    $( $body )*
+                }
+            };
+        }
+
+        pub use with_foo_preset;
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_define_preset_rejects_invalid_override() {
+    let result = define_preset(quote! {
+        @inherit [ BarAComponent ]
+        FooPreset: BasePreset {
+            override BarBComponent: BazAComponents,
+        }
+    });
+
+    assert!(result.is_err());
+
+    let result = define_preset(quote! {
+        @inherit [ BarAComponent ]
+        FooPreset: BasePreset {
+            BarAComponent: BazAComponents,
+        }
+    });
+
+    assert!(result.is_err());
+}
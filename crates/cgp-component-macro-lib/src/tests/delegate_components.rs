@@ -92,3 +92,81 @@ fn test_delegate_components_containing_generics() {
 
     assert!(equal_token_stream(&derived, &expected));
 }
+
+#[test]
+fn test_delegate_components_with_where_clause() {
+    let derived = delegate_components(quote! {
+        <Context> FooComponents<Context> {
+            [
+                <T> BarComponentA<T>,
+                <T> BarComponentB<T>,
+            ]: BazComponents<T>
+            where {
+                T: BarConstraint,
+                Context: HasBar<T>,
+            },
+            BarComponentC: BazComponentsC,
+        }
+    })
+    .unwrap();
+
+    let expected = quote! {
+        impl<Context, T> DelegateComponent<BarComponentA<T>> for FooComponents<Context>
+        where
+            T: BarConstraint,
+            Context: HasBar<T>,
+        {
+            type Delegate = BazComponents<T>;
+        }
+
+        impl<Context, T> DelegateComponent<BarComponentB<T>> for FooComponents<Context>
+        where
+            T: BarConstraint,
+            Context: HasBar<T>,
+        {
+            type Delegate = BazComponents<T>;
+        }
+
+        impl<Context> DelegateComponent<BarComponentC> for FooComponents<Context> {
+            type Delegate = BazComponentsC;
+        }
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_delegate_components_rejects_duplicate_components() {
+    let result = delegate_components(quote! {
+        FooComponents {
+            [
+                BarAComponent,
+                BarBComponent,
+            ]: BazAComponents,
+            BarAComponent: BazBComponents,
+        }
+    });
+
+    assert!(result.is_err());
+
+    let result = delegate_components(quote! {
+        FooComponents {
+            <T> BarComponent<T>: BazAComponents,
+            <U> BarComponent<U>: BazBComponents,
+        }
+    });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_delegate_components_accepts_distinct_generic_components() {
+    let result = delegate_components(quote! {
+        FooComponents {
+            <T> BarComponent<T, u8>: BazAComponents,
+            <T> BarComponent<u8, T>: BazBComponents,
+        }
+    });
+
+    assert!(result.is_ok());
+}
@@ -17,7 +17,8 @@ fn test_basic_derive_component() {
                 fn foo(&self) -> Self::Foo;
             }
         },
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -34,7 +35,8 @@ fn test_derive_component_with_const_generic() {
                 fn foo(&self) -> Self::Foo;
             }
         },
-    );
+    )
+    .unwrap();
 
     let expected = quote! {
         pub trait HasFoo<const BAR: usize> {
@@ -45,7 +47,7 @@ fn test_derive_component_with_const_generic() {
 
         pub struct FooComponent;
 
-        pub trait FooProvider<Context, const BAR: usize> {
+        pub trait FooProvider<Context, const BAR: usize>: IsProviderFor<FooComponent, Context, ()> {
             type Foo;
 
             fn foo(context: &Context) -> Self::Foo;
@@ -78,3 +80,196 @@ fn test_derive_component_with_const_generic() {
 
     assert!(equal_token_stream(&derived, &expected));
 }
+
+#[test]
+fn test_derive_component_with_lifetime_generic() {
+    let derived = derive_component(
+        quote! {
+            name: FooComponent,
+            provider: FooProvider,
+        },
+        quote! {
+            pub trait HasFoo<'a, Bar> {
+                fn foo(&self, bar: &'a Bar) -> &'a str;
+            }
+        },
+    )
+    .unwrap();
+
+    let expected = quote! {
+        pub trait HasFoo<'a, Bar> {
+            fn foo(&self, bar: &'a Bar) -> &'a str;
+        }
+
+        pub struct FooComponent;
+
+        pub trait FooProvider<'a, Context, Bar>: IsProviderFor<FooComponent, Context, (&'a (), Bar)> {
+            fn foo(context: &Context, bar: &'a Bar) -> &'a str;
+        }
+
+        impl<'a, Context, Bar> HasFoo<'a, Bar> for Context
+        where
+            Context: HasComponents,
+            Context::Components: FooProvider<'a, Context, Bar>,
+        {
+            fn foo(&self, bar: &'a Bar) -> &'a str {
+                Context::Components::foo(self, bar)
+            }
+        }
+
+        impl<'a, Component, Context, Bar> FooProvider<'a, Context, Bar> for Component
+        where
+            Component: DelegateComponent<FooComponent>,
+            Component::Delegate: FooProvider<'a, Context, Bar>,
+        {
+            fn foo(context: &Context, bar: &'a Bar) -> &'a str {
+                Component::Delegate::foo(context, bar)
+            }
+        }
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_component_with_const_and_default_method() {
+    let derived = derive_component(
+        quote! {
+            provider: FooProvider,
+        },
+        quote! {
+            pub trait HasFoo {
+                const FOO: u64;
+
+                fn foo(&self) -> u64;
+
+                #[cgp_default]
+                fn double_foo(&self) -> u64 {
+                    self.foo() * 2
+                }
+            }
+        },
+    )
+    .unwrap();
+
+    let expected = quote! {
+        pub trait HasFoo {
+            const FOO: u64;
+
+            fn foo(&self) -> u64;
+
+            fn double_foo(&self) -> u64 {
+                self.foo() * 2
+            }
+        }
+
+        pub struct FooProviderComponent;
+
+        pub trait FooProvider<Context>: IsProviderFor<FooProviderComponent, Context, ()> {
+            const FOO: u64;
+
+            fn foo(context: &Context) -> u64;
+        }
+
+        impl<Context> HasFoo for Context
+        where
+            Context: HasComponents,
+            Context::Components: FooProvider<Context>,
+        {
+            const FOO: u64 = <Context::Components as FooProvider<Context>>::FOO;
+
+            fn foo(&self) -> u64 {
+                Context::Components::foo(self)
+            }
+        }
+
+        impl<Component, Context> FooProvider<Context> for Component
+        where
+            Component: DelegateComponent<FooProviderComponent>,
+            Component::Delegate: FooProvider<Context>,
+        {
+            const FOO: u64 = <Component::Delegate as FooProvider<Context>>::FOO;
+
+            fn foo(context: &Context) -> u64 {
+                Component::Delegate::foo(context)
+            }
+        }
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_component_with_derive_delegate() {
+    let derived = derive_component(
+        quote! {
+            provider: ErrorRaiser,
+            derive_delegate: [E],
+        },
+        quote! {
+            pub trait CanRaiseError<E>: HasErrorType {
+                fn raise_error(e: E) -> Self::Error;
+            }
+        },
+    )
+    .unwrap();
+
+    let expected = quote! {
+        pub trait CanRaiseError<E>: HasErrorType {
+            fn raise_error(e: E) -> Self::Error;
+        }
+
+        pub struct ErrorRaiserComponent;
+
+        pub trait ErrorRaiser<Context, E>: IsProviderFor<ErrorRaiserComponent, Context, E>
+        where
+            Context: HasErrorType,
+        {
+            fn raise_error(e: E) -> Context::Error;
+        }
+
+        impl<Context, E> CanRaiseError<E> for Context
+        where
+            Context: HasErrorType,
+            Context: HasComponents,
+            Context::Components: ErrorRaiser<Context, E>,
+        {
+            fn raise_error(e: E) -> Self::Error {
+                Context::Components::raise_error(e)
+            }
+        }
+
+        impl<Component, Context, E> ErrorRaiser<Context, E> for Component
+        where
+            Context: HasErrorType,
+            Component: DelegateComponent<ErrorRaiserComponent>,
+            Component::Delegate: ErrorRaiser<Context, E>,
+        {
+            fn raise_error(e: E) -> Context::Error {
+                Component::Delegate::raise_error(e)
+            }
+        }
+
+        impl<Context, E, Components> ErrorRaiser<Context, E> for UseDelegate<Components>
+        where
+            Context: HasErrorType,
+            Components: DelegateComponent<E>,
+            Components::Delegate: ErrorRaiser<Context, E>,
+        {
+            fn raise_error(e: E) -> Context::Error {
+                Components::Delegate::raise_error(e)
+            }
+        }
+
+        impl<Context, E, Components> IsProviderFor<ErrorRaiserComponent, Context, E>
+            for UseDelegate<Components>
+        where
+            Context: HasErrorType,
+            Components: DelegateComponent<E>,
+            Components::Delegate: IsProviderFor<ErrorRaiserComponent, Context, E>,
+        {
+        }
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
@@ -0,0 +1,460 @@
+use quote::quote;
+
+use crate::derive_component::derive::derive_component;
+use crate::derive_getter::{derive_auto_getter, derive_getter};
+use crate::tests::helper::equal::equal_token_stream;
+
+#[test]
+fn test_derive_getter() {
+    let attr = quote! {
+        provider: NameGetter,
+    };
+
+    let item = quote! {
+        pub trait HasName {
+            fn name(&self) -> &String;
+        }
+    };
+
+    let derived = derive_getter(attr.clone(), item.clone()).unwrap();
+
+    let mut expected = derive_component(attr, item).unwrap();
+
+    expected.extend(quote! {
+        impl<Context> NameGetter<Context> for UseContext
+        where
+            Context: HasField<symbol!("name"), Value = String>,
+        {
+            fn name(context: &Context) -> &String {
+                context.get_field(::core::marker::PhantomData::<symbol!("name")>)
+            }
+        }
+
+        impl<Context> IsProviderFor<NameGetterComponent, Context, ()> for UseContext
+        where
+            Context: HasField<symbol!("name"), Value = String>,
+        {
+        }
+
+        impl<Context, __Tag> NameGetter<Context> for UseField<__Tag>
+        where
+            Context: HasField<__Tag, Value = String>,
+        {
+            fn name(context: &Context) -> &String {
+                context.get_field(::core::marker::PhantomData::<__Tag>)
+            }
+        }
+
+        impl<Context, __Tag> IsProviderFor<NameGetterComponent, Context, ()> for UseField<__Tag>
+        where
+            Context: HasField<__Tag, Value = String>,
+        {
+        }
+
+        impl<Context, __Provider> NameGetter<Context> for WithProvider<__Provider>
+        where
+            __Provider: FieldGetter<Context, NameGetterComponent, Value = String>,
+        {
+            fn name(context: &Context) -> &String {
+                <__Provider as FieldGetter<Context, NameGetterComponent>>::get_field(
+                    context,
+                    ::core::marker::PhantomData,
+                )
+            }
+        }
+
+        impl<Context, __Provider> IsProviderFor<NameGetterComponent, Context, ()>
+            for WithProvider<__Provider>
+        where
+            __Provider: FieldGetter<Context, NameGetterComponent, Value = String>,
+        {
+        }
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_str_getter() {
+    let attr = quote! {
+        provider: NameGetter,
+    };
+
+    let item = quote! {
+        pub trait HasName {
+            fn name(&self) -> &str;
+        }
+    };
+
+    let derived = derive_getter(attr.clone(), item.clone()).unwrap();
+
+    let mut expected = derive_component(attr, item).unwrap();
+
+    expected.extend(quote! {
+        impl<Context> NameGetter<Context> for UseContext
+        where
+            Context: HasField<symbol!("name"), Value: ::core::convert::AsRef<str>>,
+        {
+            fn name(context: &Context) -> &str {
+                ::core::convert::AsRef::<str>::as_ref(
+                    context.get_field(::core::marker::PhantomData::<symbol!("name")>)
+                )
+            }
+        }
+
+        impl<Context> IsProviderFor<NameGetterComponent, Context, ()> for UseContext
+        where
+            Context: HasField<symbol!("name"), Value: ::core::convert::AsRef<str>>,
+        {
+        }
+
+        impl<Context, __Tag> NameGetter<Context> for UseField<__Tag>
+        where
+            Context: HasField<__Tag, Value: ::core::convert::AsRef<str>>,
+            __Tag: 'static,
+        {
+            fn name(context: &Context) -> &str {
+                ::core::convert::AsRef::<str>::as_ref(
+                    context.get_field(::core::marker::PhantomData::<__Tag>)
+                )
+            }
+        }
+
+        impl<Context, __Tag> IsProviderFor<NameGetterComponent, Context, ()> for UseField<__Tag>
+        where
+            Context: HasField<__Tag, Value: ::core::convert::AsRef<str>>,
+            __Tag: 'static,
+        {
+        }
+
+        impl<Context, __Provider> NameGetter<Context> for WithProvider<__Provider>
+        where
+            __Provider: FieldGetter<Context, NameGetterComponent, Value: ::core::convert::AsRef<str>>,
+            __Provider: 'static,
+        {
+            fn name(context: &Context) -> &str {
+                ::core::convert::AsRef::<str>::as_ref(
+                    <__Provider as FieldGetter<Context, NameGetterComponent>>::get_field(
+                        context,
+                        ::core::marker::PhantomData,
+                    )
+                )
+            }
+        }
+
+        impl<Context, __Provider> IsProviderFor<NameGetterComponent, Context, ()>
+            for WithProvider<__Provider>
+        where
+            __Provider: FieldGetter<Context, NameGetterComponent, Value: ::core::convert::AsRef<str>>,
+            __Provider: 'static,
+        {
+        }
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_getter_with_multiple_fields() {
+    let attr = quote! {
+        provider: ConfigGetter,
+    };
+
+    let item = quote! {
+        pub trait HasConfig {
+            fn host(&self) -> &String;
+
+            fn port(&self) -> &u16;
+        }
+    };
+
+    let derived = derive_getter(attr.clone(), item.clone()).unwrap();
+
+    let mut expected = derive_component(attr, item).unwrap();
+
+    expected.extend(quote! {
+        impl<Context> ConfigGetter<Context> for UseContext
+        where
+            Context: HasField<symbol!("host"), Value = String>,
+            Context: HasField<symbol!("port"), Value = u16>,
+        {
+            fn host(context: &Context) -> &String {
+                context.get_field(::core::marker::PhantomData::<symbol!("host")>)
+            }
+
+            fn port(context: &Context) -> &u16 {
+                context.get_field(::core::marker::PhantomData::<symbol!("port")>)
+            }
+        }
+
+        impl<Context> IsProviderFor<ConfigGetterComponent, Context, ()> for UseContext
+        where
+            Context: HasField<symbol!("host"), Value = String>,
+            Context: HasField<symbol!("port"), Value = u16>,
+        {
+        }
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_getter_rejects_non_getter_items() {
+    let associated_type = derive_getter(
+        quote! {
+            provider: NameGetter,
+        },
+        quote! {
+            pub trait HasName {
+                type Name;
+
+                fn name(&self) -> &Self::Name;
+            }
+        },
+    );
+
+    assert!(associated_type.is_err());
+
+    let owned_return = derive_getter(
+        quote! {
+            provider: NameGetter,
+        },
+        quote! {
+            pub trait HasName {
+                fn name(&self) -> String;
+            }
+        },
+    );
+
+    assert!(owned_return.is_err());
+}
+
+#[test]
+fn test_derive_auto_getter() {
+    let derived = derive_auto_getter(
+        quote! {},
+        quote! {
+            pub trait HasPerson<T>: HasAge {
+                fn name(&self) -> &str;
+
+                fn tags(&self) -> &[T];
+
+                fn nickname(&self) -> Option<&String>;
+
+                fn address(&self) -> &Address;
+            }
+        },
+    )
+    .unwrap();
+
+    let expected = quote! {
+        pub trait HasPerson<T>: HasAge {
+            fn name(&self) -> &str;
+
+            fn tags(&self) -> &[T];
+
+            fn nickname(&self) -> Option<&String>;
+
+            fn address(&self) -> &Address;
+        }
+
+        impl<__Context, T> HasPerson<T> for __Context
+        where
+            __Context: HasAge,
+            __Context: HasField<symbol!("name"), Value: ::core::convert::AsRef<str>>,
+            __Context: HasField<symbol!("tags"), Value: ::core::convert::AsRef<[T]>>,
+            __Context: HasField<symbol!("nickname"), Value = ::core::option::Option<String>>,
+            __Context: HasField<symbol!("address"), Value = Address>,
+        {
+            fn name(&self) -> &str {
+                ::core::convert::AsRef::<str>::as_ref(
+                    self.get_field(::core::marker::PhantomData::<symbol!("name")>)
+                )
+            }
+
+            fn tags(&self) -> &[T] {
+                ::core::convert::AsRef::<[T]>::as_ref(
+                    self.get_field(::core::marker::PhantomData::<symbol!("tags")>)
+                )
+            }
+
+            fn nickname(&self) -> Option<&String> {
+                ::core::option::Option::as_ref(
+                    self.get_field(::core::marker::PhantomData::<symbol!("nickname")>)
+                )
+            }
+
+            fn address(&self) -> &Address {
+                self.get_field(::core::marker::PhantomData::<symbol!("address")>)
+            }
+        }
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_auto_getter_rejects_arguments() {
+    let derived = derive_auto_getter(
+        quote! { provider: NameGetter },
+        quote! {
+            pub trait HasName {
+                fn name(&self) -> &str;
+            }
+        },
+    );
+
+    assert!(derived.is_err());
+}
+
+#[test]
+fn test_derive_mutable_getter() {
+    let attr = quote! {
+        provider: CounterGetter,
+    };
+
+    let item = quote! {
+        pub trait HasCounter {
+            fn counter(&self) -> &u64;
+
+            fn counter_mut(&mut self) -> &mut u64;
+        }
+    };
+
+    let derived = derive_getter(attr.clone(), item.clone()).unwrap();
+
+    let mut expected = derive_component(attr, item).unwrap();
+
+    expected.extend(quote! {
+        impl<Context> CounterGetter<Context> for UseContext
+        where
+            Context: HasField<symbol!("counter"), Value = u64>,
+            Context: HasFieldMut<symbol!("counter"), Value = u64>,
+        {
+            fn counter(context: &Context) -> &u64 {
+                context.get_field(::core::marker::PhantomData::<symbol!("counter")>)
+            }
+
+            fn counter_mut(context: &mut Context) -> &mut u64 {
+                context.get_field_mut(::core::marker::PhantomData::<symbol!("counter")>)
+            }
+        }
+
+        impl<Context> IsProviderFor<CounterGetterComponent, Context, ()> for UseContext
+        where
+            Context: HasField<symbol!("counter"), Value = u64>,
+            Context: HasFieldMut<symbol!("counter"), Value = u64>,
+        {
+        }
+
+        impl<Context, __Tag> CounterGetter<Context> for UseField<__Tag>
+        where
+            Context: HasField<__Tag, Value = u64>,
+            Context: HasFieldMut<__Tag, Value = u64>,
+        {
+            fn counter(context: &Context) -> &u64 {
+                context.get_field(::core::marker::PhantomData::<__Tag>)
+            }
+
+            fn counter_mut(context: &mut Context) -> &mut u64 {
+                context.get_field_mut(::core::marker::PhantomData::<__Tag>)
+            }
+        }
+
+        impl<Context, __Tag> IsProviderFor<CounterGetterComponent, Context, ()> for UseField<__Tag>
+        where
+            Context: HasField<__Tag, Value = u64>,
+            Context: HasFieldMut<__Tag, Value = u64>,
+        {
+        }
+
+        impl<Context, __Provider> CounterGetter<Context> for WithProvider<__Provider>
+        where
+            __Provider: FieldGetter<Context, CounterGetterComponent, Value = u64>,
+            __Provider: MutFieldGetter<Context, CounterGetterComponent, Value = u64>,
+        {
+            fn counter(context: &Context) -> &u64 {
+                <__Provider as FieldGetter<Context, CounterGetterComponent>>::get_field(
+                    context,
+                    ::core::marker::PhantomData,
+                )
+            }
+
+            fn counter_mut(context: &mut Context) -> &mut u64 {
+                <__Provider as MutFieldGetter<Context, CounterGetterComponent>>::get_field_mut(
+                    context,
+                    ::core::marker::PhantomData,
+                )
+            }
+        }
+
+        impl<Context, __Provider> IsProviderFor<CounterGetterComponent, Context, ()>
+            for WithProvider<__Provider>
+        where
+            __Provider: FieldGetter<Context, CounterGetterComponent, Value = u64>,
+            __Provider: MutFieldGetter<Context, CounterGetterComponent, Value = u64>,
+        {
+        }
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_auto_getter_with_mutable_getters() {
+    let derived = derive_auto_getter(
+        quote! {},
+        quote! {
+            pub trait HasBuffer {
+                fn buffer_mut(&mut self) -> &mut [u8];
+
+                fn label_mut(&mut self) -> Option<&mut String>;
+            }
+        },
+    )
+    .unwrap();
+
+    let expected = quote! {
+        pub trait HasBuffer {
+            fn buffer_mut(&mut self) -> &mut [u8];
+
+            fn label_mut(&mut self) -> Option<&mut String>;
+        }
+
+        impl<__Context> HasBuffer for __Context
+        where
+            __Context: HasFieldMut<symbol!("buffer"), Value: ::core::convert::AsMut<[u8]>>,
+            __Context: HasFieldMut<symbol!("label"), Value = ::core::option::Option<String>>,
+        {
+            fn buffer_mut(&mut self) -> &mut [u8] {
+                ::core::convert::AsMut::<[u8]>::as_mut(
+                    self.get_field_mut(::core::marker::PhantomData::<symbol!("buffer")>)
+                )
+            }
+
+            fn label_mut(&mut self) -> Option<&mut String> {
+                ::core::option::Option::as_mut(
+                    self.get_field_mut(::core::marker::PhantomData::<symbol!("label")>)
+                )
+            }
+        }
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_getter_rejects_mismatched_mutability() {
+    let derived = derive_getter(
+        quote! {
+            provider: CounterGetter,
+        },
+        quote! {
+            pub trait HasCounter {
+                fn counter_mut(&self) -> &mut u64;
+            }
+        },
+    );
+
+    assert!(derived.is_err());
+}
@@ -0,0 +1,71 @@
+use quote::quote;
+
+use crate::derive_provider;
+use crate::tests::helper::equal::equal_token_stream;
+
+#[test]
+fn test_basic_derive_provider() {
+    let derived = derive_provider(
+        quote! { FooComponent },
+        quote! {
+            impl<Context> FooProvider<Context> for MyProvider
+            where
+                Context: HasBar,
+            {
+                fn foo(context: &Context) -> u64 {
+                    context.bar()
+                }
+            }
+        },
+    )
+    .unwrap();
+
+    let expected = quote! {
+        impl<Context> FooProvider<Context> for MyProvider
+        where
+            Context: HasBar,
+        {
+            fn foo(context: &Context) -> u64 {
+                context.bar()
+            }
+        }
+
+        impl<Context> IsProviderFor<FooComponent, Context, ()> for MyProvider
+        where
+            Context: HasBar,
+        {
+        }
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_provider_with_generic_params() {
+    let derived = derive_provider(
+        quote! {},
+        quote! {
+            impl<'a, Context, Tag, const N: usize> FooProvider<'a, Context, Tag, N> for MyProvider<Tag> {
+                fn foo(context: &Context, tag: &'a Tag) -> [u64; N] {
+                    [0; N]
+                }
+            }
+        },
+    )
+    .unwrap();
+
+    let expected = quote! {
+        impl<'a, Context, Tag, const N: usize> FooProvider<'a, Context, Tag, N> for MyProvider<Tag> {
+            fn foo(context: &Context, tag: &'a Tag) -> [u64; N] {
+                [0; N]
+            }
+        }
+
+        impl<'a, Context, Tag> IsProviderFor<FooProviderComponent, Context, (&'a (), Tag)>
+            for MyProvider<Tag>
+        {
+        }
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
@@ -1,5 +1,8 @@
+pub mod check_components;
 pub mod define_preset;
 pub mod delegate_components;
 pub mod derive_component;
+pub mod derive_getter;
+pub mod derive_provider;
 pub mod for_each_replace;
 pub mod helper;
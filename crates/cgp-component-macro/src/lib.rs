@@ -11,12 +11,23 @@
 
    * [`macro@cgp_component`] - Attribute macro for defining CGP components. This macro
      processes trait and impl blocks to enable component functionality.
+   * [`macro@cgp_provider`] - Attribute macro for implementing the provider trait of
+     a component, linking the provider to the component.
+   * [`macro@cgp_getter`] - Attribute macro for defining getter components, which
+     are implemented through `HasField`.
+   * [`macro@cgp_auto_getter`] - Attribute macro for implementing getter traits for
+     all contexts through `HasField`, without defining a component.
 
    ## Component Delegation
 
    * [`macro@delegate_components`] - Macro for implementing component delegation. This
      allows a type to delegate component implementations to its fields.
 
+   ## Component Checks
+
+   * [`macro@check_components`] - Macro for checking at compile time that a context
+     can use the given components.
+
    ## Component Presets
 
    * [`macro@cgp_preset`] - Macro for defining reusable component configurations. This
@@ -115,9 +126,202 @@ use proc_macro::TokenStream;
 ///     }
 /// }
 /// ```
+///
+/// Associated constants are delegated to the provider in the same way as
+/// associated types. Methods marked with `#[cgp_default]` keep their default
+/// body in the consumer trait, and are not part of the provider trait:
+///
+/// ```rust,ignore
+/// #[cgp_component { provider: LoggerProvider }]
+/// trait Logger {
+///     const PREFIX: &'static str;
+///
+///     fn log(&self, message: &str);
+///
+///     #[cgp_default]
+///     fn log_all(&self, messages: &[&str]) {
+///         for message in messages {
+///             self.log(message);
+///         }
+///     }
+/// }
+/// ```
+///
+/// Specifying `derive_delegate: [Tag]` additionally implements the provider
+/// trait for `UseDelegate<Components>`, which looks up the actual provider
+/// from `Components` using the `Tag` generic parameter:
+///
+/// ```rust,ignore
+/// #[cgp_component {
+///     name: TypeComponent,
+///     provider: ProvideType,
+///     derive_delegate: [Tag],
+/// }]
+/// pub trait HasType<Tag> {
+///     type Type;
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn cgp_component(attr: TokenStream, item: TokenStream) -> TokenStream {
-    cgp_component_macro_lib::derive_component(attr.into(), item.into()).into()
+    cgp_component_macro_lib::derive_component(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Attribute macro for defining getter components.
+///
+/// This macro accepts the same specification as [`macro@cgp_component`], and
+/// is applied to a trait containing only getter methods, which take `&self`
+/// and return a reference to a field. Besides the component, it implements
+/// the provider trait for `UseContext`, which gets each field from the context
+/// through `HasField`, using the symbol of the method name as the field tag.
+/// If all getter methods access the same field, the provider trait is also
+/// implemented for `UseField<Tag>`, which gets the field with the given tag,
+/// and for `WithProvider<Provider>`, which gets the field through the
+/// `FieldGetter` implementation of `Provider`, such as `WithField<Tag>`.
+///
+/// Mutable getter methods take `&mut self` and return a mutable reference,
+/// which is obtained through `HasFieldMut`, or `MutFieldGetter` for
+/// `WithProvider`. The `_mut` suffix of a mutable getter is removed to get the
+/// field name.
+///
+/// A mutable getter cannot be used with a field marked with `#[cgp(readonly)]`,
+/// as the `HasField` derive does not implement `HasFieldMut` for the field.
+/// This cannot be checked when the getter is defined, as the macro does not
+/// see the contexts it is used with. Instead, using the getter with such a
+/// context fails with an error that the getter trait or component is not
+/// implemented, which names the missing `HasFieldMut` implementation. The
+/// message and the note about `#[cgp(readonly)]` that `HasFieldMut` defines
+/// are only shown where `HasFieldMut` itself is required, such as in a generic
+/// bound, as the compiler reports the getter trait otherwise:
+///
+/// ```rust,ignore
+/// #[cgp_getter {
+///     provider: CounterGetter,
+/// }]
+/// pub trait HasCounter {
+///     fn counter(&self) -> &u64;
+///
+///     fn counter_mut(&mut self) -> &mut u64;
+/// }
+///
+/// #[derive(HasField)]
+/// pub struct App {
+///     #[cgp(readonly)]
+///     pub counter: u64,
+/// }
+///
+/// // Error: `App` cannot use `CounterGetterComponent`, as
+/// // `App` does not implement `HasFieldMut<symbol!("counter")>`
+/// check_components! {
+///     CanUseApp for App {
+///         CounterGetterComponent,
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[cgp_getter {
+///     provider: NameGetter,
+/// }]
+/// pub trait HasName {
+///     fn name(&self) -> &String;
+/// }
+///
+/// delegate_components! {
+///     AppComponents {
+///         // Gets `name` from `App` through `HasField<symbol!("name")>`
+///         NameGetterComponent: UseContext,
+///         // Or gets the `app_name` field instead
+///         // NameGetterComponent: UseField<symbol!("app_name")>,
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn cgp_getter(attr: TokenStream, item: TokenStream) -> TokenStream {
+    cgp_component_macro_lib::derive_getter(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Attribute macro for implementing getter traits directly through `HasField`.
+///
+/// This macro is applied to a trait containing only getter methods, and
+/// implements the trait for all contexts that have the corresponding fields,
+/// without defining a component. Each method gets the field tagged with the
+/// symbol of the method name. Besides `&T`, the getter methods can return:
+///
+/// * `&str`, from a field that implements `AsRef<str>`, such as `String`
+/// * `&[T]`, from a field that implements `AsRef<[T]>`, such as `Vec<T>`
+/// * `Option<&T>`, from a field of type `Option<T>`
+///
+/// The same return types are also supported by [`macro@cgp_getter`], together
+/// with mutable getters that take `&mut self` and are implemented through
+/// `HasFieldMut`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[cgp_auto_getter]
+/// pub trait HasName {
+///     fn name(&self) -> &str;
+/// }
+///
+/// #[derive(HasField)]
+/// pub struct Person {
+///     pub name: String,
+/// }
+///
+/// let person = Person { name: "Alice".into() };
+/// assert_eq!(person.name(), "Alice");
+/// ```
+#[proc_macro_attribute]
+pub fn cgp_auto_getter(attr: TokenStream, item: TokenStream) -> TokenStream {
+    cgp_component_macro_lib::derive_auto_getter(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Attribute macro for implementing the provider trait of a component.
+///
+/// This macro is applied to an implementation of a provider trait generated by
+/// [`macro@cgp_component`]. Besides emitting the implementation as is, it also
+/// implements `IsProviderFor` for the provider, which is required by the provider
+/// trait. As a result, implementing a provider trait that does not belong to the
+/// given component fails at the implementation site.
+///
+/// The component name defaults to the provider trait name with a `Component`
+/// suffix if it is not given.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[cgp_provider(LoggerComponent)]
+/// impl<Context> LoggerProvider<Context> for ConsoleLogger {
+///     fn log(_context: &Context, message: &str) {
+///         println!("{}", message);
+///     }
+/// }
+/// ```
+///
+/// # Migration
+///
+/// Since provider traits have `IsProviderFor` as a supertrait, existing provider
+/// implementations without `#[cgp_provider]` no longer compile. They can be
+/// migrated by adding `#[cgp_provider]` to the implementation, or by writing the
+/// `IsProviderFor` implementation by hand, with the provider trait's generic
+/// parameters other than the context packed into the last argument:
+///
+/// ```rust,ignore
+/// impl<Context> IsProviderFor<LoggerComponent, Context, ()> for ConsoleLogger {}
+/// ```
+#[proc_macro_attribute]
+pub fn cgp_provider(attr: TokenStream, item: TokenStream) -> TokenStream {
+    cgp_component_macro_lib::derive_provider(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// Macro for implementing component delegation.
@@ -136,10 +340,53 @@ pub fn cgp_component(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// An entry can be followed by a where clause, which is added to the generated
+/// implementations. Unlike a regular Rust where clause, the predicates must be
+/// wrapped in braces, as otherwise they could not be told apart from the entries
+/// that follow:
+///
+/// ```rust,ignore
+/// delegate_components! {
+///     MyComponents {
+///         <T> ValueGetterComponent<T>: UseField<T>
+///         where {
+///             T: Tag,
+///         },
+///         NameGetterComponent: UseField<symbol!("name")>,
+///     }
+/// }
+/// ```
 #[proc_macro]
 pub fn delegate_components(body: TokenStream) -> TokenStream {
     cgp_component_macro_lib::delegate_components(body.into())
-        .unwrap()
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Macro for checking at compile time that a context can use its components.
+///
+/// For each listed component, this macro asserts that the context implements
+/// `CanUseComponent` for the component. If a component is not wired for the
+/// context, the error is reported at the corresponding entry, and names the
+/// missing component. The `Params` of a component default to its generic
+/// arguments, and can be given explicitly after a colon.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// check_components! {
+///     MyApp {
+///         LoggerComponent,
+///         MetricsComponent<Counter>,
+///         TypeComponent: ErrorTag,
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn check_components(body: TokenStream) -> TokenStream {
+    cgp_component_macro_lib::check_components(body.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
 
@@ -159,10 +406,45 @@ pub fn delegate_components(body: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// A preset can extend a parent preset defined with `cgp_preset!`. All
+/// components of the parent preset are delegated to the parent, except for
+/// the entries marked with `override`. Components that are not part of the
+/// parent preset are added without `override`:
+///
+/// ```rust,ignore
+/// cgp_preset! {
+///     AppPreset: LoggingPreset {
+///         override LoggerComponent: TracingLogger,
+///         CacheComponent: InMemoryCache,
+///     }
+/// }
+/// ```
+///
+/// The entries of a preset accept the same braced where clauses as
+/// [`macro@delegate_components`]. The predicates of entries without generic
+/// parameters of their own are also added to the generated `DelegatesTo*` trait.
+/// Generic entries such as `<T> FooComponent<T>` are not part of that trait, as
+/// a bound for all `T` cannot be expressed as a supertrait:
+///
+/// ```rust,ignore
+/// cgp_preset! {
+///     AppPreset<Tag> {
+///         <T> ValueGetterComponent<T>: UseTagValue
+///         where {
+///             T: HasValue,
+///         },
+///         DefaultValueGetterComponent: UseDefaultTag<Tag>
+///         where {
+///             Tag: HasValue,
+///         },
+///     }
+/// }
+/// ```
 #[proc_macro]
 pub fn cgp_preset(body: TokenStream) -> TokenStream {
     cgp_component_macro_lib::define_preset(body.into())
-        .unwrap()
+        .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
 
@@ -184,7 +466,7 @@ pub fn cgp_preset(body: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn for_each_replace(body: TokenStream) -> TokenStream {
     cgp_component_macro_lib::handle_for_each_replace(body.into())
-        .unwrap()
+        .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
 
@@ -204,6 +486,6 @@ pub fn for_each_replace(body: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn replace_with(body: TokenStream) -> TokenStream {
     cgp_component_macro_lib::handle_replace(body.into())
-        .unwrap()
+        .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
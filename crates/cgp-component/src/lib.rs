@@ -13,6 +13,8 @@
      delegate component implementations to other types.
    * [`HasComponents`] - Trait for types that provide component implementations,
      enabling component composition and reuse.
+   * [`CanUseComponent`] - Trait for checking that a context can use a component.
+   * [`IsProviderFor`] - Marker trait linking a provider to the component it implements.
 
    # Utility Types
 
@@ -25,6 +27,8 @@
 
    The crate re-exports several macros from [`cgp_component_macro`]:
    * `cgp_component` - Derive macro for implementing component traits
+   * `cgp_provider` - Attribute macro for implementing provider traits of a component
+   * `check_components` - Macro for checking that a context can use its components
    * `cgp_preset` - Macro for defining reusable component configurations
    * `delegate_components` - Macro for implementing component delegation
    * `for_each_replace` - Utility macro for type replacement
@@ -60,7 +64,8 @@ pub mod traits;
 pub mod types;
 
 pub use cgp_component_macro::{
-    cgp_component, cgp_preset, delegate_components, for_each_replace, replace_with,
+    cgp_auto_getter, cgp_component, cgp_getter, cgp_preset, cgp_provider, check_components,
+    delegate_components, for_each_replace, replace_with,
 };
-pub use traits::{DelegateComponent, HasComponents};
+pub use traits::{CanUseComponent, DelegateComponent, HasComponents, IsProviderFor};
 pub use types::{UseContext, UseDelegate, WithContext, WithProvider};
@@ -0,0 +1,38 @@
+use crate::traits::has_components::HasComponents;
+use crate::traits::is_provider::IsProviderFor;
+
+/// Trait for checking that a context can use a component.
+///
+/// This trait is automatically implemented for any context whose `Components`
+/// is a provider for the given component. It is mainly used by the
+/// `check_components!` macro, to assert at compile time that all components
+/// of a context are wired, and to report the missing component by name.
+///
+/// # Type Parameters
+///
+/// * `Component` - The component name type, such as `FooComponent`.
+/// * `Params` - The remaining generic parameters of the provider trait, as in
+///   [`IsProviderFor`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// check_components! {
+///     MyContext {
+///         FooComponent,
+///         BarComponent,
+///     }
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "the context `{Self}` cannot use the component `{Component}`",
+    note = "check that `{Component}` is delegated to a provider that implements it for `{Self}`"
+)]
+pub trait CanUseComponent<Component, Params = ()> {}
+
+impl<Context, Component, Params> CanUseComponent<Component, Params> for Context
+where
+    Context: HasComponents,
+    Context::Components: IsProviderFor<Component, Context, Params>,
+{
+}
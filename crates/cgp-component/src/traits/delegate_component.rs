@@ -44,6 +44,10 @@
 /// This trait is typically implemented using the `delegate_components` macro,
 /// which handles the implementation details automatically. Manual implementation
 /// is possible but not recommended for most use cases.
+#[diagnostic::on_unimplemented(
+    message = "no provider is wired for the component `{Name}` in `{Self}`",
+    note = "add an entry for `{Name}` to `delegate_components!` for `{Self}`, or implement the provider trait for `{Self}` with `#[cgp_provider]`"
+)]
 pub trait DelegateComponent<Name> {
     type Delegate;
 }
@@ -0,0 +1,60 @@
+use crate::traits::delegate_component::DelegateComponent;
+
+/// Marker trait linking a provider to the component it implements.
+///
+/// Every provider trait generated by `#[cgp_component]` has `IsProviderFor` as
+/// its supertrait, with `Component` set to the component name. A provider
+/// implementation therefore only compiles if the provider is also declared to
+/// be a provider for that component, which is done by the `#[cgp_provider]`
+/// attribute macro.
+///
+/// # Type Parameters
+///
+/// * `Component` - The component name type, such as `FooComponent`.
+/// * `Context` - The context type the provider is implemented for.
+/// * `Params` - The remaining generic parameters of the provider trait, as a
+///   tuple. Lifetime parameters are represented as `&'a ()`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[cgp_component {
+///     provider: FooProvider,
+/// }]
+/// pub trait HasFoo<Tag> {
+///     fn foo(&self, tag: Tag) -> u64;
+/// }
+///
+/// // Generated provider trait:
+/// pub trait FooProvider<Context, Tag>: IsProviderFor<FooProviderComponent, Context, Tag> {
+///     fn foo(context: &Context, tag: Tag) -> u64;
+/// }
+///
+/// pub struct ConstFoo;
+///
+/// // Also implements `IsProviderFor<FooProviderComponent, Context, Tag>` for `ConstFoo`
+/// #[cgp_provider(FooProviderComponent)]
+/// impl<Context, Tag> FooProvider<Context, Tag> for ConstFoo {
+///     fn foo(_context: &Context, _tag: Tag) -> u64 {
+///         42
+///     }
+/// }
+/// ```
+///
+/// # Note
+///
+/// Types that delegate a component through [`DelegateComponent`] are
+/// automatically providers for that component, if their delegate is.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a provider of the component `{Component}` for the context `{Context}`",
+    note = "provider trait implementations need to be annotated with `#[cgp_provider]`"
+)]
+pub trait IsProviderFor<Component, Context, Params = ()> {}
+
+impl<Component, Context, Params, Components, Delegate> IsProviderFor<Component, Context, Params>
+    for Components
+where
+    Components: DelegateComponent<Component, Delegate = Delegate>,
+    Delegate: IsProviderFor<Component, Context, Params>,
+{
+}
@@ -12,6 +12,13 @@
 /// * [`HasComponents`] - Trait for declaring what components a type provides.
 ///   This enables type-safe component composition and discovery.
 ///
+/// * [`IsProviderFor`] - Marker trait linking a provider to the component it
+///   implements, so that wrongly wired providers are rejected where they are
+///   implemented.
+///
+/// * [`CanUseComponent`] - Trait for checking that a context can use a component,
+///   used by the `check_components!` macro.
+///
 /// These traits work together to provide a flexible and type-safe component
 /// system. Types can both provide components (via `HasComponents`) and delegate
 /// component implementations to other types (via `DelegateComponent`).
@@ -40,8 +47,12 @@
 ///     type Delegate = ConsoleLogger;
 /// }
 /// ```
+pub mod can_use_component;
 pub mod delegate_component;
 pub mod has_components;
+pub mod is_provider;
 
+pub use can_use_component::CanUseComponent;
 pub use delegate_component::DelegateComponent;
 pub use has_components::HasComponents;
+pub use is_provider::IsProviderFor;
@@ -0,0 +1,54 @@
+use cgp_component::*;
+
+#[cgp_component {
+    provider: FooProvider,
+}]
+pub trait HasFoo<Tag> {
+    fn foo(&self, tag: Tag) -> u64;
+}
+
+pub struct ConstFoo;
+
+#[cgp_provider(FooProviderComponent)]
+impl<Context, Tag> FooProvider<Context, Tag> for ConstFoo {
+    fn foo(_context: &Context, _tag: Tag) -> u64 {
+        42
+    }
+}
+
+mod preset {
+    use super::*;
+
+    cgp_preset! {
+        MyPreset {
+            FooProviderComponent: ConstFoo,
+        }
+    }
+}
+
+use preset::MyPreset;
+
+pub struct MyContext;
+
+pub struct MyComponents;
+
+impl HasComponents for MyContext {
+    type Components = MyComponents;
+}
+
+delegate_components! {
+    MyComponents {
+        FooProviderComponent: MyPreset,
+    }
+}
+
+check_components! {
+    MyContext {
+        FooProviderComponent: u8,
+        FooProviderComponent: (),
+    }
+}
+
+fn main() {
+    assert_eq!(MyContext.foo(1u8), 42);
+}
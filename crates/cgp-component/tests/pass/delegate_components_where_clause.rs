@@ -0,0 +1,60 @@
+use core::marker::PhantomData;
+
+use cgp_component::*;
+
+pub trait Tag {
+    const VALUE: u64;
+}
+
+pub struct FooTag;
+
+impl Tag for FooTag {
+    const VALUE: u64 = 42;
+}
+
+#[cgp_component {
+    name: ValueGetterComponent<T>,
+    provider: ValueGetter,
+}]
+pub trait HasValue<T> {
+    fn value(&self, tag: PhantomData<T>) -> u64;
+}
+
+pub struct UseTagValue;
+
+#[cgp_provider(ValueGetterComponent<T>)]
+impl<Context, T> ValueGetter<Context, T> for UseTagValue
+where
+    T: Tag,
+{
+    fn value(_context: &Context, _tag: PhantomData<T>) -> u64 {
+        T::VALUE
+    }
+}
+
+pub struct MyContext;
+
+pub struct MyComponents;
+
+impl HasComponents for MyContext {
+    type Components = MyComponents;
+}
+
+delegate_components! {
+    MyComponents {
+        <T> ValueGetterComponent<T>: UseTagValue
+        where {
+            T: Tag,
+        },
+    }
+}
+
+check_components! {
+    MyContext {
+        ValueGetterComponent<FooTag>: FooTag,
+    }
+}
+
+fn main() {
+    assert_eq!(MyContext.value(PhantomData::<FooTag>), 42);
+}
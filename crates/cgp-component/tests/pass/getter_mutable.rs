@@ -0,0 +1,113 @@
+use core::marker::PhantomData;
+
+use cgp_component::*;
+use cgp_field::*;
+
+#[cgp_getter {
+    provider: CounterGetter,
+}]
+pub trait HasCounter {
+    fn counter(&self) -> &u64;
+
+    fn counter_mut(&mut self) -> &mut u64;
+}
+
+#[cgp_getter {
+    provider: BufferGetter,
+}]
+pub trait HasBuffer {
+    fn buffer(&self) -> &[u8];
+
+    fn buffer_mut(&mut self) -> &mut [u8];
+}
+
+#[cgp_getter {
+    provider: LabelGetter,
+}]
+pub trait HasLabel {
+    fn label_mut(&mut self) -> Option<&mut String>;
+}
+
+#[derive(HasField)]
+pub struct Machine {
+    pub counter: u64,
+    pub data: Vec<u8>,
+    pub label: Option<String>,
+    #[cgp(readonly)]
+    pub id: u64,
+}
+
+pub struct MachineComponents;
+
+impl HasComponents for Machine {
+    type Components = MachineComponents;
+}
+
+delegate_components! {
+    MachineComponents {
+        CounterGetterComponent: UseContext,
+        BufferGetterComponent: UseField<symbol!("data")>,
+        LabelGetterComponent: WithField<symbol!("label")>,
+    }
+}
+
+// A field getter that goes through the `counter` field of an inner machine.
+pub struct InnerCounter;
+
+impl<OutTag> FieldGetter<Wrapper, OutTag> for InnerCounter {
+    type Value = u64;
+
+    fn get_field(context: &Wrapper, _tag: PhantomData<OutTag>) -> &u64 {
+        &context.inner.counter
+    }
+}
+
+impl<OutTag> MutFieldGetter<Wrapper, OutTag> for InnerCounter {
+    fn get_field_mut(context: &mut Wrapper, _tag: PhantomData<OutTag>) -> &mut u64 {
+        &mut context.inner.counter
+    }
+}
+
+pub struct Wrapper {
+    pub inner: Machine,
+}
+
+pub struct WrapperComponents;
+
+impl HasComponents for Wrapper {
+    type Components = WrapperComponents;
+}
+
+delegate_components! {
+    WrapperComponents {
+        CounterGetterComponent: WithProvider<InnerCounter>,
+    }
+}
+
+fn increment<Context: HasCounter>(context: &mut Context) {
+    *context.counter_mut() += 1;
+}
+
+fn main() {
+    let mut machine = Machine {
+        counter: 0,
+        data: vec![0, 0],
+        label: Some("idle".to_owned()),
+        id: 1,
+    };
+
+    increment(&mut machine);
+    assert_eq!(*machine.counter(), 1);
+
+    machine.buffer_mut()[1] = 7;
+    assert_eq!(machine.buffer(), [0, 7]);
+
+    machine.label_mut().unwrap().push_str("-running");
+    assert_eq!(machine.label.as_deref(), Some("idle-running"));
+
+    let mut wrapper = Wrapper { inner: machine };
+
+    increment(&mut wrapper);
+    assert_eq!(*wrapper.counter(), 2);
+    assert_eq!(wrapper.inner.id, 1);
+}
@@ -0,0 +1,111 @@
+use cgp_component::*;
+use cgp_field::*;
+
+#[cgp_getter {
+    provider: NameGetter,
+}]
+pub trait HasName {
+    fn name(&self) -> &str;
+}
+
+#[cgp_getter {
+    provider: TagsGetter,
+}]
+pub trait HasTags {
+    fn tags(&self) -> &[String];
+
+    fn tags_mut(&mut self) -> &mut [String];
+}
+
+#[cgp_getter {
+    provider: NicknameGetter,
+}]
+pub trait HasNickname {
+    fn nickname(&self) -> Option<&String>;
+}
+
+#[cgp_auto_getter]
+pub trait HasTitle {
+    fn title(&self) -> &str;
+}
+
+#[cgp_auto_getter]
+pub trait HasScores {
+    fn scores(&self) -> &[u32];
+}
+
+#[derive(HasField)]
+pub struct Person {
+    pub name: String,
+    pub labels: Vec<String>,
+    pub nickname: Option<String>,
+    pub title: String,
+    pub scores: Vec<u32>,
+}
+
+#[derive(HasField)]
+pub struct Borrowed<'a> {
+    pub name: &'a str,
+    pub title: &'a str,
+    pub scores: &'a [u32],
+}
+
+pub struct BorrowedComponents;
+
+impl HasComponents for Borrowed<'_> {
+    type Components = BorrowedComponents;
+}
+
+delegate_components! {
+    BorrowedComponents {
+        NameGetterComponent: UseField<symbol!("title")>,
+    }
+}
+
+pub struct PersonComponents;
+
+impl HasComponents for Person {
+    type Components = PersonComponents;
+}
+
+delegate_components! {
+    PersonComponents {
+        [
+            NameGetterComponent,
+            NicknameGetterComponent,
+        ]: UseContext,
+        TagsGetterComponent: UseField<symbol!("labels")>,
+    }
+}
+
+fn main() {
+    let mut person = Person {
+        name: "Alice".to_owned(),
+        labels: vec!["admin".to_owned()],
+        nickname: None,
+        title: "Dr".to_owned(),
+        scores: vec![1, 2, 3],
+    };
+
+    assert_eq!(person.name(), "Alice");
+    assert_eq!(person.tags(), ["admin".to_owned()]);
+
+    person.tags_mut()[0] = "owner".to_owned();
+    assert_eq!(person.labels, ["owner".to_owned()]);
+    assert_eq!(person.nickname(), None);
+    assert_eq!(person.title(), "Dr");
+    assert_eq!(person.scores(), [1, 2, 3]);
+
+    let title = "Prof".to_owned();
+    let scores = vec![4, 5];
+
+    let borrowed = Borrowed {
+        name: "Bob",
+        title: &title,
+        scores: &scores,
+    };
+
+    assert_eq!(borrowed.name(), "Prof");
+    assert_eq!(borrowed.title(), "Prof");
+    assert_eq!(borrowed.scores(), [4, 5]);
+}
@@ -0,0 +1,99 @@
+use cgp_component::*;
+
+#[cgp_component {
+    provider: FooProvider,
+}]
+pub trait HasFoo {
+    fn foo(&self) -> u64;
+}
+
+#[cgp_component {
+    provider: BarProvider,
+}]
+pub trait HasBar {
+    fn bar(&self) -> u64;
+}
+
+#[cgp_component {
+    provider: BazProvider,
+}]
+pub trait HasBaz {
+    fn baz(&self) -> u64;
+}
+
+pub struct Provide<const N: u64>;
+
+#[cgp_provider(FooProviderComponent)]
+impl<Context, const N: u64> FooProvider<Context> for Provide<N> {
+    fn foo(_context: &Context) -> u64 {
+        N
+    }
+}
+
+#[cgp_provider(BarProviderComponent)]
+impl<Context, const N: u64> BarProvider<Context> for Provide<N> {
+    fn bar(_context: &Context) -> u64 {
+        N
+    }
+}
+
+#[cgp_provider(BazProviderComponent)]
+impl<Context, const N: u64> BazProvider<Context> for Provide<N> {
+    fn baz(_context: &Context) -> u64 {
+        N
+    }
+}
+
+mod base {
+    use super::*;
+
+    cgp_preset! {
+        BasePreset {
+            [
+                FooProviderComponent,
+                BarProviderComponent,
+            ]: Provide<1>,
+        }
+    }
+}
+
+mod middle {
+    use super::*;
+
+    cgp_preset! {
+        MiddlePreset: base::BasePreset {
+            override BarProviderComponent: Provide<2>,
+            BazProviderComponent: Provide<3>,
+        }
+    }
+}
+
+mod app {
+    use super::*;
+
+    cgp_preset! {
+        AppPreset: middle::MiddlePreset {
+            override FooProviderComponent: Provide<4>,
+        }
+    }
+}
+
+pub struct MyContext;
+
+impl HasComponents for MyContext {
+    type Components = app::AppPreset;
+}
+
+check_components! {
+    MyContext {
+        FooProviderComponent,
+        BarProviderComponent,
+        BazProviderComponent,
+    }
+}
+
+fn main() {
+    assert_eq!(MyContext.foo(), 4);
+    assert_eq!(MyContext.bar(), 2);
+    assert_eq!(MyContext.baz(), 3);
+}
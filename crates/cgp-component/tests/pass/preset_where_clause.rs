@@ -0,0 +1,112 @@
+use core::marker::PhantomData;
+
+use cgp_component::*;
+
+pub trait Tag {
+    const VALUE: u64;
+}
+
+pub struct FooTag;
+
+impl Tag for FooTag {
+    const VALUE: u64 = 42;
+}
+
+pub struct BarTag;
+
+impl Tag for BarTag {
+    const VALUE: u64 = 7;
+}
+
+#[cgp_component {
+    name: ValueGetterComponent<T>,
+    provider: ValueGetter,
+}]
+pub trait HasValue<T> {
+    fn value(&self, tag: PhantomData<T>) -> u64;
+}
+
+#[cgp_component {
+    provider: DefaultValueGetter,
+}]
+pub trait HasDefaultValue {
+    fn default_value(&self) -> u64;
+}
+
+pub struct UseTagValue;
+
+#[cgp_provider(ValueGetterComponent<T>)]
+impl<Context, T> ValueGetter<Context, T> for UseTagValue
+where
+    T: Tag,
+{
+    fn value(_context: &Context, _tag: PhantomData<T>) -> u64 {
+        T::VALUE
+    }
+}
+
+pub struct UseDefaultTag<T>(pub PhantomData<T>);
+
+#[cgp_provider(DefaultValueGetterComponent)]
+impl<Context, T> DefaultValueGetter<Context> for UseDefaultTag<T>
+where
+    T: Tag,
+{
+    fn default_value(_context: &Context) -> u64 {
+        T::VALUE
+    }
+}
+
+mod preset {
+    use super::*;
+
+    cgp_preset! {
+        MyPreset<D> {
+            <T> ValueGetterComponent<T>: UseTagValue
+            where {
+                T: Tag,
+            },
+            DefaultValueGetterComponent: UseDefaultTag<D>
+            where {
+                D: Tag,
+            },
+        }
+    }
+}
+
+pub struct MyContext;
+
+impl HasComponents for MyContext {
+    type Components = preset::MyPreset<BarTag>;
+}
+
+check_components! {
+    MyContext {
+        ValueGetterComponent<FooTag>: FooTag,
+        DefaultValueGetterComponent,
+    }
+}
+
+pub struct OtherComponents;
+
+delegate_components! {
+    OtherComponents {
+        DefaultValueGetterComponent: preset::MyPreset<BarTag>,
+    }
+}
+
+// The where clause of `DefaultValueGetterComponent` is merged into the bounds
+// of `DelegatesToMyPreset`.
+fn assert_delegates_to_preset<Components, D>()
+where
+    Components: preset::DelegatesToMyPreset<D>,
+    D: Tag,
+{
+}
+
+fn main() {
+    assert_delegates_to_preset::<OtherComponents, BarTag>();
+
+    assert_eq!(MyContext.value(PhantomData::<FooTag>), 42);
+    assert_eq!(MyContext.default_value(), 7);
+}
@@ -0,0 +1,42 @@
+use cgp_component::*;
+
+#[cgp_component {
+    provider: ArrayProvider,
+}]
+pub trait HasArray<const N: usize> {
+    fn array(&self) -> [u64; N];
+}
+
+pub struct Zeros;
+
+#[cgp_provider]
+impl<Context, const N: usize> ArrayProvider<Context, N> for Zeros {
+    fn array(_context: &Context) -> [u64; N] {
+        [0; N]
+    }
+}
+
+pub struct MyContext;
+
+pub struct MyComponents;
+
+impl HasComponents for MyContext {
+    type Components = MyComponents;
+}
+
+delegate_components! {
+    MyComponents {
+        ArrayProviderComponent: Zeros,
+    }
+}
+
+check_components! {
+    MyContext {
+        ArrayProviderComponent,
+    }
+}
+
+fn main() {
+    assert_eq!(MyContext.array(), [0; 3]);
+    assert_eq!(MyContext.array(), [0; 0]);
+}
@@ -0,0 +1,47 @@
+use cgp_component::*;
+
+#[cgp_component {
+    provider: FooProvider,
+}]
+pub trait HasFoo {
+    fn foo(&self) -> u64;
+}
+
+#[cgp_component {
+    provider: BarProvider,
+}]
+pub trait HasBar {
+    fn bar(&self) -> u64;
+}
+
+pub struct ConstFoo;
+
+#[cgp_provider(FooProviderComponent)]
+impl<Context> FooProvider<Context> for ConstFoo {
+    fn foo(_context: &Context) -> u64 {
+        42
+    }
+}
+
+pub struct MyContext;
+
+pub struct MyComponents;
+
+impl HasComponents for MyContext {
+    type Components = MyComponents;
+}
+
+delegate_components! {
+    MyComponents {
+        FooProviderComponent: ConstFoo,
+    }
+}
+
+check_components! {
+    MyContext {
+        FooProviderComponent,
+        BarProviderComponent,
+    }
+}
+
+fn main() {}
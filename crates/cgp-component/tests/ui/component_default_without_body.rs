@@ -0,0 +1,13 @@
+use cgp_component::*;
+
+#[cgp_component {
+    provider: FooProvider,
+}]
+pub trait HasFoo {
+    fn foo(&self) -> u64;
+
+    #[cgp_default]
+    fn double_foo(&self) -> u64;
+}
+
+fn main() {}
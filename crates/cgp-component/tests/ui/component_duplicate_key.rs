@@ -0,0 +1,12 @@
+use cgp_component::*;
+
+#[cgp_component {
+    name: FooComponent,
+    provider: FooProvider,
+    name: BarComponent,
+}]
+pub trait HasFoo {
+    fn foo(&self) -> u64;
+}
+
+fn main() {}
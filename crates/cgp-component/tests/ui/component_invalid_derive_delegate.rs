@@ -0,0 +1,11 @@
+use cgp_component::*;
+
+#[cgp_component {
+    provider: FooProvider,
+    derive_delegate: Tag,
+}]
+pub trait HasFoo<Tag> {
+    fn foo(&self, tag: Tag) -> u64;
+}
+
+fn main() {}
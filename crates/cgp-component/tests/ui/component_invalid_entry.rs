@@ -0,0 +1,11 @@
+use cgp_component::*;
+
+#[cgp_component {
+    name: FooComponent,
+    provider = FooProvider,
+}]
+pub trait HasFoo {
+    fn foo(&self) -> u64;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use cgp_component::*;
+
+#[cgp_component {
+    provider: FooProvider<Bar>,
+}]
+pub trait HasFoo {
+    fn foo(&self) -> u64;
+}
+
+fn main() {}
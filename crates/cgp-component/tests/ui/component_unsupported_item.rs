@@ -0,0 +1,12 @@
+use cgp_component::*;
+
+#[cgp_component {
+    provider: FooProvider,
+}]
+pub trait HasFoo {
+    fn foo(&self) -> u64;
+
+    foo_macro!();
+}
+
+fn main() {}
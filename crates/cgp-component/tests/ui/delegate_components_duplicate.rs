@@ -0,0 +1,21 @@
+use cgp_component::*;
+
+pub struct FooComponents;
+
+pub struct BarComponent;
+pub struct BazComponent<T>(pub T);
+
+pub struct BazProvider;
+
+delegate_components! {
+    FooComponents {
+        [
+            BarComponent,
+            <T> BazComponent<T>,
+        ]: BazProvider,
+        BarComponent: BazProvider,
+        <U> BazComponent<U>: BazProvider,
+    }
+}
+
+fn main() {}
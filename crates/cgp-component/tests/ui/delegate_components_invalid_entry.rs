@@ -0,0 +1,11 @@
+use cgp_component::*;
+
+pub struct FooComponents;
+
+delegate_components! {
+    FooComponents {
+        BarComponent => BazProvider,
+    }
+}
+
+fn main() {}
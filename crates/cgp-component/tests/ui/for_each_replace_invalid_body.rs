@@ -0,0 +1,10 @@
+use cgp_component::*;
+
+for_each_replace! {
+    [u8, u16],
+    Foo {
+        impl Foo for u8 {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use cgp_component::*;
+
+#[cgp_getter {
+    provider: NameGetter,
+}]
+pub trait HasName {
+    fn name(&self) -> String;
+}
+
+fn main() {}
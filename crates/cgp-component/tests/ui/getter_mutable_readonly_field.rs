@@ -0,0 +1,30 @@
+use core::marker::PhantomData;
+
+use cgp_component::*;
+use cgp_field::*;
+
+#[cgp_auto_getter]
+pub trait HasId {
+    fn id_mut(&mut self) -> &mut u64;
+}
+
+#[derive(HasField)]
+pub struct Machine {
+    #[cgp(readonly)]
+    pub id: u64,
+}
+
+fn bump_id<Context: HasId>(context: &mut Context) {
+    *context.id_mut() += 1;
+}
+
+fn reset_id<Context: HasFieldMut<symbol!("id"), Value = u64>>(context: &mut Context) {
+    *context.get_field_mut(PhantomData) = 0;
+}
+
+fn main() {
+    let mut machine = Machine { id: 1 };
+
+    bump_id(&mut machine);
+    reset_id(&mut machine);
+}
@@ -0,0 +1,9 @@
+use cgp_component::*;
+
+cgp_preset! {
+    FooPreset {
+        BarComponent BazProvider,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use cgp_component::*;
+
+pub struct FooComponent;
+pub struct BarComponent;
+pub struct FooImpl;
+
+mod base {
+    use super::*;
+
+    cgp_preset! {
+        BasePreset {
+            FooComponent: FooImpl,
+        }
+    }
+}
+
+mod extended {
+    use super::*;
+
+    cgp_preset! {
+        ExtendedPreset: base::BasePreset {
+            override BarComponent: FooImpl,
+        }
+    }
+}
+
+fn main() {}
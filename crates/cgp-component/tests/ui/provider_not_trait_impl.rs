@@ -0,0 +1,12 @@
+use cgp_component::*;
+
+pub struct MyProvider;
+
+#[cgp_provider(FooComponent)]
+impl MyProvider {
+    pub fn foo() -> u64 {
+        42
+    }
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use cgp_component::*;
+
+#[cgp_component {
+    provider: FooProvider,
+}]
+pub trait HasFoo {
+    fn foo(&self) -> u64;
+}
+
+#[cgp_component {
+    provider: BarProvider,
+}]
+pub trait HasBar {
+    fn bar(&self) -> u64;
+}
+
+pub struct MyProvider;
+
+#[cgp_provider(BarProviderComponent)]
+impl<Context> FooProvider<Context> for MyProvider {
+    fn foo(_context: &Context) -> u64 {
+        42
+    }
+}
+
+fn main() {}
@@ -1,9 +1,13 @@
 pub use cgp_async::{async_trait, Async, MaybeSend, MaybeStatic, MaybeSync};
 pub use cgp_component::{
-    cgp_component, cgp_preset, delegate_components, for_each_replace, replace_with,
-    DelegateComponent, HasComponents,
+    cgp_auto_getter, cgp_component, cgp_getter, cgp_preset, cgp_provider, check_components,
+    delegate_components, for_each_replace, replace_with, CanUseComponent, DelegateComponent,
+    HasComponents, IsProviderFor, UseContext, WithProvider,
 };
-pub use cgp_error::{CanRaiseError, HasErrorType};
+pub use cgp_error::{CanRaiseError, CanWrapError, HasErrorType};
 pub use cgp_field::{
-    product, symbol, Char, Cons, Either, HasField, HasFieldMut, Nil, Product, Sum, Void,
+    flatten_fields, product, symbol, Char, Chars, Cons, Either, Field, FieldGetter, FromFields,
+    FromVariants, HasField, HasFieldMut, HasFields, HasVariants, Index, IntoFields, IntoVariants,
+    MutFieldGetter, Nil, Product, StaticFormat, Sum, Symbol, ToFieldsRef, UseField, Void,
+    WithField,
 };
@@ -0,0 +1,133 @@
+use core::fmt::{Debug, Display};
+use std::error::Error as StdError;
+
+use anyhow::{anyhow, Error};
+use cgp_core::error::{
+    ErrorRaiser, ErrorRaiserComponent, ErrorTypeComponent, ErrorWrapper, ErrorWrapperComponent,
+    ProvideErrorType,
+};
+use cgp_core::prelude::*;
+
+pub struct ProvideAnyhowError;
+
+#[cgp_provider(ErrorTypeComponent)]
+impl<Context> ProvideErrorType<Context> for ProvideAnyhowError {
+    type Error = Error;
+}
+
+/**
+   Raises an [`anyhow::Error`] into a context, passing through the original
+   error if it is of the context's error type.
+
+   Since [`anyhow::Error`] does not implement [`std::error::Error`], it cannot
+   be raised through [`RaiseStdError`]. Instead, the error is downcast to the
+   context's error type, so that an error that was converted into
+   [`anyhow::Error`] is recovered as is. Otherwise, the error is converted with
+   `From<anyhow::Error>`. For contexts that use [`anyhow::Error`] as the error
+   type, this passes through the original error without re-wrapping it.
+
+   ```
+   use std::num::ParseIntError;
+
+   use cgp_core::error::{ErrorRaiserComponent, ErrorTypeComponent};
+   use cgp_core::prelude::*;
+   use cgp_error_anyhow::{ProvideAnyhowError, RaiseAnyhowError};
+
+   pub struct App;
+
+   pub struct AppComponents;
+
+   impl HasComponents for App {
+       type Components = AppComponents;
+   }
+
+   delegate_components! {
+       AppComponents {
+           ErrorTypeComponent: ProvideAnyhowError,
+           ErrorRaiserComponent: RaiseAnyhowError,
+       }
+   }
+
+   let parse_error = "abc".parse::<u64>().unwrap_err();
+   let error = App::raise_error(anyhow::Error::new(parse_error.clone()));
+
+   assert_eq!(error.downcast_ref::<ParseIntError>(), Some(&parse_error));
+   ```
+*/
+pub struct RaiseAnyhowError;
+
+#[cgp_provider(ErrorRaiserComponent)]
+impl<Context> ErrorRaiser<Context, Error> for RaiseAnyhowError
+where
+    Context: HasErrorType,
+    Context::Error: From<Error> + Display + Debug + Send + Sync + 'static,
+{
+    fn raise_error(e: Error) -> Context::Error {
+        e.downcast::<Context::Error>().unwrap_or_else(From::from)
+    }
+}
+
+pub struct RaiseStdError;
+
+#[cgp_provider(ErrorRaiserComponent)]
+impl<Context, E> ErrorRaiser<Context, E> for RaiseStdError
+where
+    Context: HasErrorType<Error = Error>,
+    E: StdError + Send + Sync + 'static,
+{
+    fn raise_error(e: E) -> Error {
+        e.into()
+    }
+}
+
+pub struct RaiseDebugError;
+
+#[cgp_provider(ErrorRaiserComponent)]
+impl<Context, E> ErrorRaiser<Context, E> for RaiseDebugError
+where
+    Context: HasErrorType<Error = Error>,
+    E: Debug,
+{
+    fn raise_error(e: E) -> Error {
+        anyhow!("{:?}", e)
+    }
+}
+
+pub struct RaiseDisplayError;
+
+#[cgp_provider(ErrorRaiserComponent)]
+impl<Context, E> ErrorRaiser<Context, E> for RaiseDisplayError
+where
+    Context: HasErrorType<Error = Error>,
+    E: Display,
+{
+    fn raise_error(e: E) -> Error {
+        anyhow!("{e}")
+    }
+}
+
+pub struct WrapDisplayError;
+
+#[cgp_provider(ErrorWrapperComponent)]
+impl<Context, Detail> ErrorWrapper<Context, Detail> for WrapDisplayError
+where
+    Context: HasErrorType<Error = Error>,
+    Detail: Display + Send + Sync + 'static,
+{
+    fn wrap_error(error: Error, detail: Detail) -> Error {
+        error.context(detail)
+    }
+}
+
+pub struct WrapDebugError;
+
+#[cgp_provider(ErrorWrapperComponent)]
+impl<Context, Detail> ErrorWrapper<Context, Detail> for WrapDebugError
+where
+    Context: HasErrorType<Error = Error>,
+    Detail: Debug,
+{
+    fn wrap_error(error: Error, detail: Detail) -> Error {
+        error.context(format!("{detail:?}"))
+    }
+}
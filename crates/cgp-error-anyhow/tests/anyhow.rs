@@ -0,0 +1,131 @@
+use core::fmt::{self, Display, Formatter};
+
+use anyhow::{anyhow, Error};
+use cgp_core::error::{
+    ErrorRaiserComponent, ErrorTypeComponent, ErrorWrapperComponent, ProvideErrorType,
+};
+use cgp_core::prelude::*;
+use cgp_error_anyhow::{ProvideAnyhowError, RaiseAnyhowError, WrapDebugError, WrapDisplayError};
+
+#[derive(Debug, PartialEq)]
+pub enum AppError {
+    NotFound,
+    Other(String),
+}
+
+impl Display for AppError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "not found"),
+            Self::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<Error> for AppError {
+    fn from(error: Error) -> Self {
+        Self::Other(error.to_string())
+    }
+}
+
+pub struct UseAppError;
+
+#[cgp_provider(ErrorTypeComponent)]
+impl<Context> ProvideErrorType<Context> for UseAppError {
+    type Error = AppError;
+}
+
+pub struct App;
+
+pub struct AppComponents;
+
+impl HasComponents for App {
+    type Components = AppComponents;
+}
+
+delegate_components! {
+    AppComponents {
+        ErrorTypeComponent: UseAppError,
+        ErrorRaiserComponent: RaiseAnyhowError,
+    }
+}
+
+pub struct DisplayApp;
+
+pub struct DisplayAppComponents;
+
+impl HasComponents for DisplayApp {
+    type Components = DisplayAppComponents;
+}
+
+delegate_components! {
+    DisplayAppComponents {
+        ErrorTypeComponent: ProvideAnyhowError,
+        ErrorRaiserComponent: RaiseAnyhowError,
+        ErrorWrapperComponent: WrapDisplayError,
+    }
+}
+
+pub struct DebugApp;
+
+pub struct DebugAppComponents;
+
+impl HasComponents for DebugApp {
+    type Components = DebugAppComponents;
+}
+
+delegate_components! {
+    DebugAppComponents {
+        ErrorTypeComponent: ProvideAnyhowError,
+        ErrorWrapperComponent: WrapDebugError,
+    }
+}
+
+#[derive(Debug)]
+pub struct Step {
+    pub index: usize,
+}
+
+fn chain(error: &Error) -> Vec<String> {
+    error.chain().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_raise_anyhow_error_recovers_context_error() {
+    let error = App::raise_error(Error::new(AppError::NotFound));
+
+    assert_eq!(error, AppError::NotFound);
+}
+
+#[test]
+fn test_raise_anyhow_error_converts_other_errors() {
+    let error = App::raise_error(anyhow!("connection refused"));
+
+    assert_eq!(error, AppError::Other("connection refused".to_owned()));
+}
+
+#[test]
+fn test_raise_anyhow_error_passes_through_anyhow_errors() {
+    let error = DisplayApp::raise_error(Error::new(AppError::NotFound));
+
+    assert_eq!(error.downcast_ref::<AppError>(), Some(&AppError::NotFound));
+}
+
+#[test]
+fn test_wrap_display_error() {
+    let error = DisplayApp::wrap_error(anyhow!("connection refused"), "while loading config");
+
+    assert_eq!(
+        chain(&error),
+        ["while loading config", "connection refused"]
+    );
+}
+
+#[test]
+fn test_wrap_debug_error() {
+    let error = DebugApp::wrap_error(anyhow!("connection refused"), Step { index: 2 });
+
+    assert_eq!(chain(&error), ["Step { index: 2 }", "connection refused"]);
+}
@@ -1,18 +1,23 @@
 use core::fmt::{Debug, Display};
 use std::error::Error as StdError;
 
-use cgp_core::error::{ErrorRaiser, ProvideErrorType};
+use cgp_core::error::{
+    ErrorRaiser, ErrorRaiserComponent, ErrorTypeComponent, ErrorWrapper, ErrorWrapperComponent,
+    ProvideErrorType,
+};
 use cgp_core::prelude::*;
 use eyre::{eyre, Report};
 
 pub struct ProvideEyreError;
 
+#[cgp_provider(ErrorTypeComponent)]
 impl<Context> ProvideErrorType<Context> for ProvideEyreError {
     type Error = Report;
 }
 
 pub struct RaiseStdError;
 
+#[cgp_provider(ErrorRaiserComponent)]
 impl<Context, E> ErrorRaiser<Context, E> for RaiseStdError
 where
     Context: HasErrorType<Error = Report>,
@@ -25,6 +30,7 @@ where
 
 pub struct RaiseDebugError;
 
+#[cgp_provider(ErrorRaiserComponent)]
 impl<Context, E> ErrorRaiser<Context, E> for RaiseDebugError
 where
     Context: HasErrorType<Error = Report>,
@@ -37,6 +43,7 @@ where
 
 pub struct RaiseDisplayError;
 
+#[cgp_provider(ErrorRaiserComponent)]
 impl<Context, E> ErrorRaiser<Context, E> for RaiseDisplayError
 where
     Context: HasErrorType<Error = Report>,
@@ -46,3 +53,29 @@ where
         eyre!("{e}")
     }
 }
+
+pub struct WrapDisplayError;
+
+#[cgp_provider(ErrorWrapperComponent)]
+impl<Context, Detail> ErrorWrapper<Context, Detail> for WrapDisplayError
+where
+    Context: HasErrorType<Error = Report>,
+    Detail: Display + Send + Sync + 'static,
+{
+    fn wrap_error(error: Report, detail: Detail) -> Report {
+        error.wrap_err(detail)
+    }
+}
+
+pub struct WrapDebugError;
+
+#[cgp_provider(ErrorWrapperComponent)]
+impl<Context, Detail> ErrorWrapper<Context, Detail> for WrapDebugError
+where
+    Context: HasErrorType<Error = Report>,
+    Detail: Debug,
+{
+    fn wrap_error(error: Report, detail: Detail) -> Report {
+        error.wrap_err(format!("{detail:?}"))
+    }
+}
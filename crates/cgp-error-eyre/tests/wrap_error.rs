@@ -0,0 +1,62 @@
+use cgp_core::error::{ErrorTypeComponent, ErrorWrapperComponent};
+use cgp_core::prelude::*;
+use cgp_error_eyre::{ProvideEyreError, WrapDebugError, WrapDisplayError};
+use eyre::{eyre, Report};
+
+pub struct DisplayApp;
+
+pub struct DisplayAppComponents;
+
+impl HasComponents for DisplayApp {
+    type Components = DisplayAppComponents;
+}
+
+delegate_components! {
+    DisplayAppComponents {
+        ErrorTypeComponent: ProvideEyreError,
+        ErrorWrapperComponent: WrapDisplayError,
+    }
+}
+
+pub struct DebugApp;
+
+pub struct DebugAppComponents;
+
+impl HasComponents for DebugApp {
+    type Components = DebugAppComponents;
+}
+
+delegate_components! {
+    DebugAppComponents {
+        ErrorTypeComponent: ProvideEyreError,
+        ErrorWrapperComponent: WrapDebugError,
+    }
+}
+
+#[derive(Debug)]
+pub struct Step {
+    pub index: usize,
+}
+
+fn chain(report: &Report) -> Vec<String> {
+    report.chain().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_wrap_error_chain() {
+    let report = DisplayApp::wrap_error(eyre!("connection refused"), "while loading config");
+    let report = DisplayApp::wrap_error(report, "while starting app");
+
+    assert_eq!(
+        chain(&report),
+        [
+            "while starting app",
+            "while loading config",
+            "connection refused"
+        ]
+    );
+
+    let report = DebugApp::wrap_error(eyre!("connection refused"), Step { index: 2 });
+
+    assert_eq!(chain(&report), ["Step { index: 2 }", "connection refused"]);
+}
@@ -4,14 +4,19 @@ extern crate alloc;
 
 use alloc::boxed::Box;
 use core::error::Error as StdError;
+use core::fmt::{self, Debug, Display, Formatter};
 
-use cgp_core::error::{ErrorRaiser, ProvideErrorType};
+use cgp_core::error::{
+    ErrorRaiser, ErrorRaiserComponent, ErrorTypeComponent, ErrorWrapper, ErrorWrapperComponent,
+    ProvideErrorType,
+};
 use cgp_core::prelude::*;
 
 pub type Error = Box<dyn StdError + Send + Sync + 'static>;
 
 pub struct HandleErrorsWithStdError;
 
+#[cgp_provider(ErrorTypeComponent)]
 impl<Context> ProvideErrorType<Context> for HandleErrorsWithStdError
 where
     Context: Async,
@@ -19,6 +24,7 @@ where
     type Error = Error;
 }
 
+#[cgp_provider(ErrorRaiserComponent)]
 impl<Context, E> ErrorRaiser<Context, E> for HandleErrorsWithStdError
 where
     Context: HasErrorType<Error = Error>,
@@ -28,3 +34,79 @@ where
         e.into()
     }
 }
+
+#[cgp_provider(ErrorWrapperComponent)]
+impl<Context, Detail> ErrorWrapper<Context, Detail> for HandleErrorsWithStdError
+where
+    Context: HasErrorType<Error = Error>,
+    Detail: Display + Debug + Send + Sync + 'static,
+{
+    fn wrap_error(error: Error, detail: Detail) -> Error {
+        Box::new(WrappedError {
+            detail,
+            source: error,
+        })
+    }
+}
+
+/**
+   An error that attaches a detail to a source error.
+
+   The wrapped error is displayed as the detail, and the original error is
+   returned by [`source`](StdError::source), so that the full chain of errors
+   can be reported by walking through the sources.
+
+   ```
+   use core::error::Error as _;
+
+   use cgp_core::error::{ErrorTypeComponent, ErrorWrapperComponent};
+   use cgp_core::prelude::*;
+   use cgp_error_std::{Error, HandleErrorsWithStdError};
+
+   pub struct App;
+
+   pub struct AppComponents;
+
+   impl HasComponents for App {
+       type Components = AppComponents;
+   }
+
+   delegate_components! {
+       AppComponents {
+           [
+               ErrorTypeComponent,
+               ErrorWrapperComponent,
+           ]: HandleErrorsWithStdError,
+       }
+   }
+
+   let error: Error = "connection refused".into();
+   let error = App::wrap_error(error, "failed to fetch config");
+
+   assert_eq!(error.to_string(), "failed to fetch config");
+   assert_eq!(error.source().unwrap().to_string(), "connection refused");
+   ```
+*/
+#[derive(Debug)]
+pub struct WrappedError<Detail> {
+    pub detail: Detail,
+    pub source: Error,
+}
+
+impl<Detail> Display for WrappedError<Detail>
+where
+    Detail: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.detail, f)
+    }
+}
+
+impl<Detail> StdError for WrappedError<Detail>
+where
+    Detail: Display + Debug,
+{
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
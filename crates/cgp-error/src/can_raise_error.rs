@@ -1,4 +1,4 @@
-use cgp_component::{cgp_component, DelegateComponent, HasComponents, UseDelegate};
+use cgp_component::{cgp_component, DelegateComponent, HasComponents, IsProviderFor, UseDelegate};
 
 use crate::has_error_type::HasErrorType;
 
@@ -11,19 +11,9 @@ use crate::has_error_type::HasErrorType;
    a [`Context::Error`](HasErrorType::Error) value.
 */
 #[cgp_component {
-    provider: ErrorRaiser
+    provider: ErrorRaiser,
+    derive_delegate: [E],
 }]
 pub trait CanRaiseError<E>: HasErrorType {
     fn raise_error(e: E) -> Self::Error;
 }
-
-impl<Context, Error, Components, Delegate> ErrorRaiser<Context, Error> for UseDelegate<Components>
-where
-    Context: HasErrorType,
-    Components: DelegateComponent<Error, Delegate = Delegate>,
-    Delegate: ErrorRaiser<Context, Error>,
-{
-    fn raise_error(e: Error) -> Context::Error {
-        Delegate::raise_error(e)
-    }
-}
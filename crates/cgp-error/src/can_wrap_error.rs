@@ -0,0 +1,22 @@
+use cgp_component::{cgp_component, DelegateComponent, HasComponents, IsProviderFor, UseDelegate};
+
+use crate::has_error_type::HasErrorType;
+
+/**
+   Used for attaching additional details to an existing
+   [`Self::Error`](HasErrorType::Error) value.
+
+   This is similar to methods like `eyre`'s `wrap_err`, which adds a
+   "while doing X" layer to an error. As an example, if
+   `Context: CanWrapError<String>`, then we would be able to call
+   `Context::wrap_error(err, "failed to load config".to_owned())` to get back
+   a [`Context::Error`](HasErrorType::Error) value that contains both the
+   original error and the detail.
+*/
+#[cgp_component {
+    provider: ErrorWrapper,
+    derive_delegate: [Detail],
+}]
+pub trait CanWrapError<Detail>: HasErrorType {
+    fn wrap_error(error: Self::Error, detail: Detail) -> Self::Error;
+}
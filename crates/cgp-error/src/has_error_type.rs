@@ -1,7 +1,9 @@
 use core::fmt::Debug;
 
 use cgp_async::Async;
-use cgp_component::{cgp_component, DelegateComponent, HasComponents, WithProvider};
+use cgp_component::{
+    cgp_component, cgp_provider, DelegateComponent, HasComponents, IsProviderFor, WithProvider,
+};
 use cgp_type::traits::has_type::ProvideType;
 
 /**
@@ -31,6 +33,7 @@ pub trait HasErrorType {
 
 pub type ErrorOf<Context> = <Context as HasErrorType>::Error;
 
+#[cgp_provider(ErrorTypeComponent)]
 impl<Context, Provider, Error> ProvideErrorType<Context> for WithProvider<Provider>
 where
     Provider: ProvideType<Context, ErrorTypeComponent, Type = Error>,
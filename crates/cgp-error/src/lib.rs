@@ -1,5 +1,7 @@
 mod can_raise_error;
+mod can_wrap_error;
 mod has_error_type;
 
 pub use can_raise_error::*;
+pub use can_wrap_error::*;
 pub use has_error_type::*;
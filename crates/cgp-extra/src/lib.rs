@@ -1 +1,3 @@
+#[cfg(feature = "anyhow")]
+pub use cgp_error_anyhow as error_anyhow;
 pub use cgp_run as run;
@@ -0,0 +1,36 @@
+#![cfg(feature = "anyhow")]
+
+use anyhow::anyhow;
+use cgp_core::error::{ErrorRaiserComponent, ErrorTypeComponent, ErrorWrapperComponent};
+use cgp_core::prelude::*;
+use cgp_extra::error_anyhow::{ProvideAnyhowError, RaiseStdError, WrapDisplayError};
+
+pub struct App;
+
+pub struct AppComponents;
+
+impl HasComponents for App {
+    type Components = AppComponents;
+}
+
+delegate_components! {
+    AppComponents {
+        ErrorTypeComponent: ProvideAnyhowError,
+        ErrorRaiserComponent: RaiseStdError,
+        ErrorWrapperComponent: WrapDisplayError,
+    }
+}
+
+#[test]
+fn test_anyhow_reexport() {
+    let parse_error = "abc".parse::<u64>().unwrap_err();
+
+    let error = App::raise_error(parse_error.clone());
+    assert_eq!(error.downcast_ref(), Some(&parse_error));
+
+    let error = App::wrap_error(anyhow!("connection refused"), "while loading config");
+    assert_eq!(
+        format!("{error:#}"),
+        "while loading config: connection refused"
+    );
+}
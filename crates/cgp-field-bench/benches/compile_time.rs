@@ -0,0 +1,45 @@
+//! Measures the time taken to compile `cgp-field-bench` with each symbol encoding.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+const PACKAGE: &str = "cgp-field-bench";
+
+fn cargo(args: &[&str]) {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let target_dir = Path::new(manifest_dir).join("../../target/compile-time-bench");
+
+    let status = Command::new(cargo)
+        .args(args)
+        .arg("--target-dir")
+        .arg(target_dir)
+        .current_dir(manifest_dir)
+        .status()
+        .expect("failed to run cargo");
+
+    assert!(status.success(), "cargo {} failed", args.join(" "));
+}
+
+fn measure(features: &[&str]) -> Duration {
+    let mut build_args = vec!["build", "--quiet", "-p", PACKAGE];
+    build_args.extend(features);
+
+    // Build once so that only this crate is rebuilt in the measured build.
+    cargo(&build_args);
+    cargo(&["clean", "--quiet", "-p", PACKAGE]);
+
+    let start = Instant::now();
+    cargo(&build_args);
+    start.elapsed()
+}
+
+fn main() {
+    let default = measure(&[]);
+    let compact = measure(&["--features", "compact-symbols"]);
+
+    println!("default symbols: {default:?}");
+    println!("compact symbols: {compact:?}");
+}
@@ -0,0 +1,33 @@
+//! Generates a struct with many long field names, which derives the field
+//! traits of `cgp-field`.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+const FIELD_COUNT: usize = 200;
+
+fn main() {
+    let mut source = String::new();
+
+    writeln!(
+        source,
+        "/// A struct with {FIELD_COUNT} fields with long names."
+    )
+    .unwrap();
+    writeln!(source, "#[derive(HasField)]").unwrap();
+    writeln!(source, "pub struct LargeStruct {{").unwrap();
+
+    for i in 0..FIELD_COUNT {
+        writeln!(
+            source,
+            "    pub field_with_a_rather_long_descriptive_name_{i:03}: u64,"
+        )
+        .unwrap();
+    }
+
+    writeln!(source, "}}").unwrap();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("large_struct.rs"), source).unwrap();
+}
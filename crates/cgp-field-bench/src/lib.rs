@@ -0,0 +1,26 @@
+/*!
+   Compile time benchmark for the symbol encodings of `cgp-field`.
+
+   This crate defines a struct with 200 fields with long names, which derives
+   `HasField`. Every field name is encoded as a type-level symbol, so the
+   compile time of this crate is dominated by the size of the symbol types.
+
+   The `compile_time` benchmark builds this crate with both the default symbol
+   encoding and the compact encoding enabled by the `compact-symbols` feature,
+   and reports the time taken by each build:
+
+   ```text
+   cargo bench -p cgp-field-bench
+   ```
+
+   The builds can also be inspected individually with `cargo build --timings`:
+
+   ```text
+   cargo build -p cgp-field-bench --timings
+   cargo build -p cgp-field-bench --timings --features compact-symbols
+   ```
+*/
+
+use cgp_field::*;
+
+include!(concat!(env!("OUT_DIR"), "/large_struct.rs"));
@@ -6,17 +6,20 @@
    mutable field access using type-safe field identifiers.
 */
 
-use proc_macro2::TokenStream;
-use quote::ToTokens;
-use syn::{parse_quote, Fields, ItemImpl, ItemStruct};
+use proc_macro2::{Literal, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Error, Field, Item, ItemImpl, ItemStruct, Member, Type};
 
+use crate::field_attributes::parse_field_attributes;
+use crate::flatten::{define_fields_macro, direct_field_entries, flatten_field_sources};
 use crate::symbol::symbol_from_string;
 
 /// Generate field access trait implementations for a struct.
 ///
 /// This function generates implementations of `HasField` and `HasFieldMut`
-/// for each named field in the input struct. It creates type-safe field
-/// access using symbol types as field identifiers.
+/// for each field in the input struct. It creates type-safe field access
+/// using symbol types as identifiers for named fields, and `Index<N>` as
+/// identifiers for the fields of tuple structs.
 ///
 /// # Arguments
 ///
@@ -24,7 +27,19 @@ use crate::symbol::symbol_from_string;
 ///
 /// # Returns
 ///
-/// A vector of implementation blocks for the field access traits.
+/// A vector of implementation blocks for the field access traits, or an error
+/// if the field attributes are invalid.
+///
+/// # Field Attributes
+///
+/// The generated implementations can be customized with `#[cgp(...)]` field
+/// attributes:
+///
+/// * `rename = "..."` - Uses the symbol of the given name as the tag
+/// * `skip` - Omits the field from the generated implementations
+/// * `readonly` - Omits the `HasFieldMut` implementation
+/// * `flatten` - Omits the field, whose tags are instead forwarded by the
+///   implementations generated through [`flatten_fields`](crate::flatten::flatten_fields)
 ///
 /// # Generated Code
 ///
@@ -44,59 +59,86 @@ use crate::symbol::symbol_from_string;
 ///     }
 /// }
 /// ```
-pub fn derive_has_field_impls(item_struct: &ItemStruct) -> Vec<ItemImpl> {
+pub fn derive_has_field_impls(item_struct: &ItemStruct) -> syn::Result<Vec<ItemImpl>> {
     let struct_ident = &item_struct.ident;
 
     let (impl_generics, ty_generics, where_clause) = item_struct.generics.split_for_impl();
 
     let mut item_impls = Vec::new();
 
-    if let Fields::Named(fields) = &item_struct.fields {
-        for field in fields.named.iter() {
-            let field_ident = field.ident.as_ref().unwrap();
+    for (i, field) in item_struct.fields.iter().enumerate() {
+        let attributes = parse_field_attributes(field)?;
 
-            let field_symbol = symbol_from_string(&field_ident.to_string());
+        if attributes.skip || attributes.flatten {
+            continue;
+        }
 
-            let field_type = &field.ty;
+        let (field_tag, field_member) = field_tag_and_member(i, field);
 
-            let has_field_impl: ItemImpl = parse_quote! {
-                impl #impl_generics HasField< #field_symbol >
-                    for #struct_ident #ty_generics
-                #where_clause
-                {
-                    type Value = #field_type;
+        let field_tag = match &attributes.rename {
+            Some(rename) => symbol_from_string(&rename.value()),
+            None => field_tag,
+        };
 
-                    fn get_field(
-                        &self,
-                        key: ::core::marker::PhantomData< #field_symbol >,
-                    ) -> &Self::Value
-                    {
-                        &self. #field_ident
-                    }
+        let field_type = &field.ty;
+
+        let has_field_impl: ItemImpl = parse_quote! {
+            impl #impl_generics HasField< #field_tag >
+                for #struct_ident #ty_generics
+            #where_clause
+            {
+                type Value = #field_type;
+
+                fn get_field(
+                    &self,
+                    key: ::core::marker::PhantomData< #field_tag >,
+                ) -> &Self::Value
+                {
+                    &self. #field_member
                 }
-            };
+            }
+        };
+
+        item_impls.push(has_field_impl);
 
+        if !attributes.readonly {
             let has_field_mut_impl: ItemImpl = parse_quote! {
-                impl #impl_generics HasFieldMut< #field_symbol >
+                impl #impl_generics HasFieldMut< #field_tag >
                     for #struct_ident #ty_generics
                 #where_clause
                 {
                     fn get_field_mut(
                         &mut self,
-                        key: ::core::marker::PhantomData< #field_symbol >,
+                        key: ::core::marker::PhantomData< #field_tag >,
                     ) -> &mut Self::Value
                     {
-                        &mut self. #field_ident
+                        &mut self. #field_member
                     }
                 }
             };
 
-            item_impls.push(has_field_impl);
             item_impls.push(has_field_mut_impl);
         }
     }
 
-    item_impls
+    Ok(item_impls)
+}
+
+/// Get the tag type and the member for accessing the field at the given position.
+///
+/// Named fields are tagged with the symbol of the field name, while the fields
+/// of tuple structs are tagged with `Index<N>`.
+pub fn field_tag_and_member(i: usize, field: &Field) -> (Type, Member) {
+    match &field.ident {
+        Some(field_ident) => (
+            symbol_from_string(&field_ident.to_string()),
+            Member::Named(field_ident.clone()),
+        ),
+        None => {
+            let index = Literal::usize_unsuffixed(i);
+            (parse_quote!(Index< #index >), Member::Unnamed(i.into()))
+        }
+    }
 }
 
 /// Derive field access traits for a struct.
@@ -111,16 +153,43 @@ pub fn derive_has_field_impls(item_struct: &ItemStruct) -> Vec<ItemImpl> {
 ///
 /// # Returns
 ///
-/// Token stream containing the generated trait implementations
+/// Token stream containing the generated trait implementations, or an error
+/// if the input is an enum or a union, which have no fields that can always
+/// be accessed.
 ///
 /// # Generated Traits
 ///
 /// * `HasField` - For immutable field access
 /// * `HasFieldMut` - For mutable field access
-pub fn derive_fields(input: TokenStream) -> TokenStream {
-    let item_struct: ItemStruct = syn::parse2(input).unwrap();
+///
+/// Besides the trait implementations, this also defines the callback macro
+/// that allows the struct to be flattened into other structs. If the struct
+/// has flattened fields itself, the macro is instead defined by
+/// `flatten_fields!`, once the tags of the flattened fields are resolved.
+pub fn derive_fields(input: TokenStream) -> syn::Result<TokenStream> {
+    let item_struct = match syn::parse2(input)? {
+        Item::Struct(item_struct) => item_struct,
+        Item::Enum(item_enum) => {
+            return Err(Error::new_spanned(
+                item_enum.enum_token,
+                "`HasField` cannot be derived for enums, as variant fields may be absent",
+            ))
+        }
+        Item::Union(item_union) => {
+            return Err(Error::new_spanned(
+                item_union.union_token,
+                "`HasField` cannot be derived for unions",
+            ))
+        }
+        item => {
+            return Err(Error::new_spanned(
+                item,
+                "`HasField` can only be derived for structs",
+            ))
+        }
+    };
 
-    let item_impls = derive_has_field_impls(&item_struct);
+    let item_impls = derive_has_field_impls(&item_struct)?;
 
     let mut output = TokenStream::new();
 
@@ -128,5 +197,39 @@ pub fn derive_fields(input: TokenStream) -> TokenStream {
         output.extend(item_impl.to_token_stream());
     }
 
-    output
+    let mut resolved = Vec::new();
+    let mut pending = Vec::new();
+    let mut has_flatten = false;
+
+    for (i, field) in item_struct.fields.iter().enumerate() {
+        let attributes = parse_field_attributes(field)?;
+
+        if attributes.flatten {
+            has_flatten = true;
+
+            flatten_field_sources(
+                &item_struct,
+                i,
+                field,
+                attributes.readonly,
+                &mut resolved,
+                &mut pending,
+            )?;
+        }
+    }
+
+    if has_flatten {
+        output.extend(quote! {
+            flatten_fields! {
+                [ #( #resolved ),* ]
+                [ #( #pending ),* ]
+                #item_struct
+            }
+        });
+    } else {
+        let entries = direct_field_entries(&item_struct)?;
+        output.extend(define_fields_macro(&item_struct, &entries));
+    }
+
+    Ok(output)
 }
@@ -0,0 +1,74 @@
+/*!
+   Parsing of the `#[cgp(...)]` field attributes.
+
+   These attributes customize how a field is exposed by the `HasField` derive.
+*/
+
+use syn::{Error, Field, LitStr};
+
+/// The options given through the `#[cgp(...)]` attributes of a field.
+#[derive(Default)]
+pub struct FieldAttributes {
+    /// Exposes the field under the symbol of the given name.
+    pub rename: Option<LitStr>,
+    /// Omits the field from the derived implementations.
+    pub skip: bool,
+    /// Omits the `HasFieldMut` implementation of the field.
+    pub readonly: bool,
+    /// Exposes all fields of the field's type through the parent struct.
+    pub flatten: bool,
+}
+
+/// Parse the `#[cgp(...)]` attributes of a field.
+///
+/// The supported options are `rename = "..."`, `skip`, `readonly` and `flatten`,
+/// which can be given in one or more `#[cgp(...)]` attributes. Other attributes
+/// of the field are ignored.
+pub fn parse_field_attributes(field: &Field) -> syn::Result<FieldAttributes> {
+    let mut attributes = FieldAttributes::default();
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cgp"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                if attributes.rename.is_some() {
+                    return Err(meta.error("duplicate `rename` option"));
+                }
+
+                attributes.rename = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("skip") {
+                attributes.skip = true;
+            } else if meta.path.is_ident("readonly") {
+                attributes.readonly = true;
+            } else if meta.path.is_ident("flatten") {
+                attributes.flatten = true;
+            } else {
+                return Err(meta.error(
+                    "unknown field option; expected one of `rename`, `skip`, `readonly` or `flatten`",
+                ));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    if attributes.skip && (attributes.rename.is_some() || attributes.readonly || attributes.flatten)
+    {
+        return Err(Error::new_spanned(
+            field,
+            "`skip` cannot be combined with other field options",
+        ));
+    }
+
+    if let (true, Some(rename)) = (attributes.flatten, &attributes.rename) {
+        return Err(Error::new_spanned(
+            rename,
+            "a flattened field cannot be renamed, as it is not exposed by its own name",
+        ));
+    }
+
+    Ok(attributes)
+}
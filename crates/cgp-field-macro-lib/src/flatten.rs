@@ -0,0 +1,428 @@
+/*!
+   Forwarding of the fields of flattened fields.
+
+   The tags that a flattened field forwards are only known by the `HasField`
+   derive of the field's type. To make them available to the parent struct,
+   the derive defines a hidden callback macro of the same name as the struct,
+   which passes the tags of the struct to the `flatten_fields!` macro. The
+   parent struct resolves the tags of its flattened fields by invoking the
+   callback macros one after another, and then generates one forwarding
+   implementation per tag.
+*/
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::__private::parse_brackets;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::{Brace, Comma, FatArrow, Mut};
+use syn::{braced, parse_quote, Error, Field, ItemImpl, ItemStruct, LitInt, Path, Type};
+
+use crate::field::field_tag_and_member;
+use crate::field_attributes::parse_field_attributes;
+use crate::symbol::symbol_from_string;
+
+/// A tag exposed by a struct, and whether it is also exposed through
+/// `HasFieldMut`.
+///
+/// Parsed from the syntax `mut Tag` for mutable tags, and `Tag` otherwise.
+pub struct FieldEntry {
+    pub mutable: bool,
+    pub tag: Type,
+}
+
+/// An item belonging to the flattened field at the given position of the
+/// parent struct, which is either a forwarded tag or the path of a struct
+/// whose tags are yet to be resolved.
+///
+/// Parsed from the syntax `index => mut item` for mutable items, and
+/// `index => item` otherwise.
+pub struct FlattenItem<T> {
+    pub index: usize,
+    pub mutable: bool,
+    pub item: T,
+}
+
+/// The state of the resolution of the flattened fields of a struct.
+///
+/// Parsed from the syntax:
+///
+/// ```text
+/// [ resolved tags ] [ pending paths ] struct ...
+/// ```
+///
+/// When invoked from a callback macro, the tags exposed by the struct of the
+/// last pending path are given in front, together with the position and the
+/// mutability of the flattened field that they belong to:
+///
+/// ```text
+/// { tags } { index mut } [ resolved tags ] [ pending paths ] struct ...
+/// ```
+pub struct FlattenSpecs {
+    pub resolved: Vec<FlattenItem<Type>>,
+    pub pending: Vec<FlattenItem<Path>>,
+    pub item_struct: ItemStruct,
+}
+
+impl Parse for FieldEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mutable = Option::<Mut>::parse(input)?.is_some();
+        let tag = Type::parse(input)?;
+
+        Ok(FieldEntry { mutable, tag })
+    }
+}
+
+impl ToTokens for FieldEntry {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.mutable {
+            tokens.extend(quote!(mut));
+        }
+
+        self.tag.to_tokens(tokens);
+    }
+}
+
+impl<T: Parse> Parse for FlattenItem<T> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let index = LitInt::parse(input)?.base10_parse()?;
+        FatArrow::parse(input)?;
+        let mutable = Option::<Mut>::parse(input)?.is_some();
+        let item = T::parse(input)?;
+
+        Ok(FlattenItem {
+            index,
+            mutable,
+            item,
+        })
+    }
+}
+
+impl<T: ToTokens> ToTokens for FlattenItem<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let index = Literal::usize_unsuffixed(self.index);
+        let item = &self.item;
+
+        tokens.extend(quote!( #index => ));
+
+        if self.mutable {
+            tokens.extend(quote!(mut));
+        }
+
+        item.to_tokens(tokens);
+    }
+}
+
+impl Parse for FlattenSpecs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut resolved = Vec::new();
+
+        if input.peek(Brace) {
+            let entries = {
+                let content;
+                braced!(content in input);
+                <Punctuated<FieldEntry, Comma>>::parse_terminated(&content)?
+            };
+
+            let (index, mutable) = {
+                let content;
+                braced!(content in input);
+                let index: usize = LitInt::parse(&content)?.base10_parse()?;
+                let mutable = Option::<Mut>::parse(&content)?.is_some();
+                (index, mutable)
+            };
+
+            resolved.extend(entries.into_iter().map(|entry| FlattenItem {
+                index,
+                mutable: mutable && entry.mutable,
+                item: entry.tag,
+            }));
+        }
+
+        {
+            let content = parse_brackets(input)?.content;
+            let items = <Punctuated<FlattenItem<Type>, Comma>>::parse_terminated(&content)?;
+            resolved.splice(0..0, items);
+        }
+
+        let pending = {
+            let content = parse_brackets(input)?.content;
+            let items = <Punctuated<FlattenItem<Path>, Comma>>::parse_terminated(&content)?;
+            items.into_iter().collect()
+        };
+
+        let item_struct = ItemStruct::parse(input)?;
+
+        Ok(FlattenSpecs {
+            resolved,
+            pending,
+            item_struct,
+        })
+    }
+}
+
+/// Resolve the tags of the flattened fields of a struct.
+///
+/// If there are pending paths left, this invokes the callback macro of the
+/// last pending path, which calls back `flatten_fields!` with the tags of its
+/// struct added. Otherwise, this generates the implementations that forward
+/// the resolved tags to the flattened fields, together with the callback macro
+/// of the struct itself.
+pub fn flatten_fields(tokens: TokenStream) -> syn::Result<TokenStream> {
+    let mut specs: FlattenSpecs = syn::parse2(tokens)?;
+
+    if let Some(source) = specs.pending.pop() {
+        let path = &source.item;
+        let index = Literal::usize_unsuffixed(source.index);
+        let mutable = source.mutable.then(|| quote!(mut));
+
+        let resolved = &specs.resolved;
+        let pending = &specs.pending;
+        let item_struct = &specs.item_struct;
+
+        return Ok(quote! {
+            #path ! {
+                { #index #mutable }
+                [ #( #resolved ),* ]
+                [ #( #pending ),* ]
+                #item_struct
+            }
+        });
+    }
+
+    let item_struct = &specs.item_struct;
+    let fields: Vec<&Field> = item_struct.fields.iter().collect();
+
+    let mut output = TokenStream::new();
+
+    for resolved in &specs.resolved {
+        let field = fields.get(resolved.index).ok_or_else(|| {
+            Error::new_spanned(&item_struct.ident, "invalid position of flattened field")
+        })?;
+
+        for item_impl in derive_forward_impls(item_struct, resolved, field) {
+            output.extend(item_impl.to_token_stream());
+        }
+    }
+
+    let mut entries = direct_field_entries(item_struct)?;
+
+    entries.extend(specs.resolved.into_iter().map(|resolved| FieldEntry {
+        mutable: resolved.mutable,
+        tag: resolved.item,
+    }));
+
+    output.extend(define_fields_macro(item_struct, &entries));
+
+    Ok(output)
+}
+
+/// Get the tags of the fields of a struct that are exposed by their own name.
+pub fn direct_field_entries(item_struct: &ItemStruct) -> syn::Result<Vec<FieldEntry>> {
+    let mut entries = Vec::new();
+
+    for (i, field) in item_struct.fields.iter().enumerate() {
+        let attributes = parse_field_attributes(field)?;
+
+        if attributes.skip || attributes.flatten {
+            continue;
+        }
+
+        let tag = match &attributes.rename {
+            Some(rename) => symbol_from_string(&rename.value()),
+            None => field_tag_and_member(i, field).0,
+        };
+
+        entries.push(FieldEntry {
+            mutable: !attributes.readonly,
+            tag,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Get the initial resolution state of a flattened field.
+///
+/// The tags of a tuple type are known right away, while the tags of a struct
+/// type are resolved through the callback macro named by the type's path.
+/// The fields of a generic type parameter are not known to the derive, and so
+/// cannot be flattened.
+pub fn flatten_field_sources(
+    item_struct: &ItemStruct,
+    index: usize,
+    field: &Field,
+    readonly: bool,
+    resolved: &mut Vec<FlattenItem<Type>>,
+    pending: &mut Vec<FlattenItem<Path>>,
+) -> syn::Result<()> {
+    match &field.ty {
+        Type::Tuple(type_tuple) => {
+            resolved.extend((0..type_tuple.elems.len()).map(|i| {
+                let i = Literal::usize_unsuffixed(i);
+
+                FlattenItem {
+                    index,
+                    mutable: !readonly,
+                    item: parse_quote!(Index< #i >),
+                }
+            }));
+
+            Ok(())
+        }
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            if let Some(ident) = type_path.path.get_ident() {
+                if item_struct
+                    .generics
+                    .type_params()
+                    .any(|param| &param.ident == ident)
+                {
+                    return Err(Error::new_spanned(
+                        &field.ty,
+                        "a field of a generic type cannot be flattened, as its fields are not known",
+                    ));
+                }
+            }
+
+            let mut path = type_path.path.clone();
+
+            if let Some(segment) = path.segments.last_mut() {
+                segment.arguments = Default::default();
+            }
+
+            pending.push(FlattenItem {
+                index,
+                mutable: !readonly,
+                item: path,
+            });
+
+            Ok(())
+        }
+        field_type => Err(Error::new_spanned(
+            field_type,
+            "a flattened field must be of a struct type that derives `HasField`, or of a tuple type",
+        )),
+    }
+}
+
+/// Generate the implementations that forward a tag to a flattened field.
+fn derive_forward_impls(
+    item_struct: &ItemStruct,
+    resolved: &FlattenItem<Type>,
+    field: &Field,
+) -> Vec<ItemImpl> {
+    let struct_ident = &item_struct.ident;
+    let tag = &resolved.item;
+    let field_type = &field.ty;
+    let (_, field_member) = field_tag_and_member(resolved.index, field);
+
+    let (_, ty_generics, _) = item_struct.generics.split_for_impl();
+
+    let mut has_field_generics = item_struct.generics.clone();
+    has_field_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!( #field_type: HasField< #tag > ));
+
+    let (impl_generics, _, where_clause) = has_field_generics.split_for_impl();
+
+    let has_field_impl: ItemImpl = parse_quote! {
+        impl #impl_generics HasField< #tag >
+            for #struct_ident #ty_generics
+        #where_clause
+        {
+            type Value = < #field_type as HasField< #tag > >::Value;
+
+            fn get_field(
+                &self,
+                key: ::core::marker::PhantomData< #tag >,
+            ) -> &Self::Value
+            {
+                < #field_type as HasField< #tag > >::get_field(&self. #field_member, key)
+            }
+        }
+    };
+
+    if !resolved.mutable {
+        return vec![has_field_impl];
+    }
+
+    let mut has_field_mut_generics = item_struct.generics.clone();
+    has_field_mut_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!( #field_type: HasFieldMut< #tag > ));
+
+    let (impl_generics, _, where_clause) = has_field_mut_generics.split_for_impl();
+
+    let has_field_mut_impl: ItemImpl = parse_quote! {
+        impl #impl_generics HasFieldMut< #tag >
+            for #struct_ident #ty_generics
+        #where_clause
+        {
+            fn get_field_mut(
+                &mut self,
+                key: ::core::marker::PhantomData< #tag >,
+            ) -> &mut Self::Value
+            {
+                < #field_type as HasFieldMut< #tag > >::get_field_mut(&mut self. #field_member, key)
+            }
+        }
+    };
+
+    vec![has_field_impl, has_field_mut_impl]
+}
+
+/// Define the callback macro that passes the tags exposed by a struct to
+/// `flatten_fields!`.
+///
+/// The macro has the same name as the struct, so that it is brought into scope
+/// together with the struct. Since macros live in their own namespace, it is
+/// defined in a hidden module and imported from there, which does not conflict
+/// with the struct itself.
+///
+/// # Examples
+///
+/// For a struct `Config` with the fields `host` and `port`, this generates:
+///
+/// ```text
+/// mod __cgp_fields_Config {
+///     macro_rules! Config {
+///         ( $( $body:tt )* ) => {
+///             flatten_fields! {
+///                 { mut symbol!("host"), mut symbol!("port"), }
+///                 $( $body )*
+///             }
+///         };
+///     }
+///
+///     pub(crate) use Config;
+/// }
+///
+/// pub(crate) use __cgp_fields_Config::Config;
+/// ```
+pub fn define_fields_macro(item_struct: &ItemStruct, entries: &[FieldEntry]) -> TokenStream {
+    let struct_ident = &item_struct.ident;
+    let module_ident = format_ident!("__cgp_fields_{}", struct_ident);
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case, unused_macros)]
+        mod #module_ident {
+            macro_rules! #struct_ident {
+                ( $( $body:tt )* ) => {
+                    flatten_fields! {
+                        { #( #entries , )* }
+                        $( $body )*
+                    }
+                };
+            }
+
+            pub(crate) use #struct_ident;
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #module_ident :: #struct_ident;
+    }
+}
@@ -0,0 +1,167 @@
+/*!
+   Structural representation trait derivation implementation.
+
+   This module implements the derivation of the `HasFields` family of traits,
+   which convert a struct to and from a product of its fields.
+*/
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Error, Ident, Item, ItemImpl, ItemStruct, Lifetime, Type};
+
+use crate::field::field_tag_and_member;
+use crate::field_attributes::parse_field_attributes;
+use crate::symbol::symbol_from_string;
+
+/// Generate the structural representation trait implementations for a struct.
+///
+/// The `Fields` type of the struct is a product of `Field` types, with each
+/// field tagged in the same way as the field access traits. For example, for
+/// a struct with fields `name: String` and `age: u8`, the generated `Fields` is:
+///
+/// ```rust,ignore
+/// Cons<Field<symbol!("name"), String>, Cons<Field<symbol!("age"), u8>, Nil>>
+/// ```
+///
+/// A field renamed with `#[cgp(rename = "...")]` is tagged with the new name,
+/// consistent with `HasField`. Fields cannot be skipped or flattened, as the
+/// struct could then not be constructed from its fields.
+///
+/// # Generated Traits
+///
+/// * `HasFields` - Defines the `Fields` type
+/// * `IntoFields` - Converts the struct into its fields
+/// * `FromFields` - Constructs the struct from its fields
+/// * `ToFieldsRef` - Borrows the struct as references to its fields
+pub fn derive_has_fields_impls(item_struct: &ItemStruct) -> syn::Result<Vec<ItemImpl>> {
+    let struct_ident = &item_struct.ident;
+
+    let (impl_generics, ty_generics, where_clause) = item_struct.generics.split_for_impl();
+
+    let lifetime = Lifetime::new("'__fields", Span::call_site());
+
+    let mut fields_type: Type = parse_quote!(Nil);
+    let mut fields_ref_type: Type = parse_quote!(Nil);
+    let mut into_fields_expr = quote!(Nil);
+    let mut to_fields_ref_expr = quote!(Nil);
+    let mut fields_pattern = quote!(Nil);
+    let mut from_fields_inits = Vec::new();
+
+    for (i, field) in item_struct.fields.iter().enumerate().rev() {
+        let attributes = parse_field_attributes(field)?;
+
+        if attributes.skip || attributes.flatten {
+            return Err(Error::new_spanned(
+                field,
+                "`HasFields` does not support skipped or flattened fields",
+            ));
+        }
+
+        let (field_tag, field_member) = field_tag_and_member(i, field);
+        let field_tag = match &attributes.rename {
+            Some(rename) => symbol_from_string(&rename.value()),
+            None => field_tag,
+        };
+        let field_type = &field.ty;
+        let field_var = Ident::new(&format!("field_{i}"), Span::call_site());
+
+        fields_type = parse_quote!(Cons< Field< #field_tag, #field_type >, #fields_type >);
+        fields_ref_type =
+            parse_quote!(Cons< Field< #field_tag, & #lifetime #field_type >, #fields_ref_type >);
+        into_fields_expr = quote!(Cons( Field::from(self. #field_member), #into_fields_expr ));
+        to_fields_ref_expr = quote!(Cons( Field::from(&self. #field_member), #to_fields_ref_expr ));
+        fields_pattern = quote!(Cons( #field_var, #fields_pattern ));
+        from_fields_inits.insert(0, quote!( #field_member: #field_var.value ));
+    }
+
+    let has_fields_impl: ItemImpl = parse_quote! {
+        impl #impl_generics HasFields for #struct_ident #ty_generics
+        #where_clause
+        {
+            type Fields = #fields_type;
+        }
+    };
+
+    let into_fields_impl: ItemImpl = parse_quote! {
+        impl #impl_generics IntoFields for #struct_ident #ty_generics
+        #where_clause
+        {
+            fn into_fields(self) -> Self::Fields {
+                #into_fields_expr
+            }
+        }
+    };
+
+    let from_fields_impl: ItemImpl = parse_quote! {
+        impl #impl_generics FromFields for #struct_ident #ty_generics
+        #where_clause
+        {
+            fn from_fields(fields: Self::Fields) -> Self {
+                let #fields_pattern = fields;
+
+                Self {
+                    #( #from_fields_inits ),*
+                }
+            }
+        }
+    };
+
+    let to_fields_ref_impl: ItemImpl = parse_quote! {
+        impl #impl_generics ToFieldsRef for #struct_ident #ty_generics
+        #where_clause
+        {
+            type FieldsRef< #lifetime > = #fields_ref_type
+            where
+                Self: #lifetime;
+
+            fn to_fields_ref(&self) -> Self::FieldsRef<'_> {
+                #to_fields_ref_expr
+            }
+        }
+    };
+
+    Ok(vec![
+        has_fields_impl,
+        into_fields_impl,
+        from_fields_impl,
+        to_fields_ref_impl,
+    ])
+}
+
+/// Derive the structural representation traits for a struct.
+///
+/// # Arguments
+///
+/// * `input` - Token stream containing the struct definition
+///
+/// # Returns
+///
+/// Token stream containing the generated trait implementations, or an error
+/// if the input is not a struct or has invalid field attributes.
+pub fn derive_has_fields(input: TokenStream) -> syn::Result<TokenStream> {
+    let item_struct = match syn::parse2(input)? {
+        Item::Struct(item_struct) => item_struct,
+        Item::Enum(item_enum) => {
+            return Err(Error::new_spanned(
+                item_enum.enum_token,
+                "`HasFields` cannot be derived for enums",
+            ))
+        }
+        item => {
+            return Err(Error::new_spanned(
+                item,
+                "`HasFields` can only be derived for structs",
+            ))
+        }
+    };
+
+    let item_impls = derive_has_fields_impls(&item_struct)?;
+
+    let mut output = TokenStream::new();
+
+    for item_impl in item_impls {
+        output.extend(item_impl.to_token_stream());
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,226 @@
+/*!
+   Structural representation trait derivation for enums.
+
+   This module implements the derivation of the `HasVariants` family of traits,
+   which convert an enum to and from a sum of its variants.
+*/
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Error, Fields, Ident, Item, ItemEnum, ItemImpl, Type, Variant};
+
+use crate::symbol::symbol_from_string;
+
+/// The payload of a variant, together with the tokens for converting a
+/// variant to and from the payload.
+struct VariantPayload {
+    payload_type: Type,
+    variant_pattern: TokenStream,
+    payload_expr: TokenStream,
+    payload_pattern: TokenStream,
+    variant_expr: TokenStream,
+}
+
+/// Generate the structural representation trait implementations for an enum.
+///
+/// The `Variants` type of the enum is a sum of `Field` types, with each variant
+/// tagged with the symbol of the variant name. For example, for an enum with
+/// variants `Circle(f64)` and `Square(f64)`, the generated `Variants` is:
+///
+/// ```rust,ignore
+/// Either<Field<symbol!("Circle"), f64>, Either<Field<symbol!("Square"), f64>, Void>>
+/// ```
+///
+/// # Generated Traits
+///
+/// * `HasVariants` - Defines the `Variants` type
+/// * `IntoVariants` - Converts the enum into its variants
+/// * `FromVariants` - Constructs the enum from its variants
+pub fn derive_has_variants_impls(item_enum: &ItemEnum) -> Vec<ItemImpl> {
+    let enum_ident = &item_enum.ident;
+
+    let (impl_generics, ty_generics, where_clause) = item_enum.generics.split_for_impl();
+
+    let mut variant_types = Vec::new();
+    let mut into_variants_arms = Vec::new();
+    let mut from_variants_arms = Vec::new();
+
+    for (i, variant) in item_enum.variants.iter().enumerate() {
+        let variant_tag = symbol_from_string(&variant.ident.to_string());
+
+        let VariantPayload {
+            payload_type,
+            variant_pattern,
+            payload_expr,
+            payload_pattern,
+            variant_expr,
+        } = derive_variant_payload(variant);
+
+        variant_types.push(quote!(Field< #variant_tag, #payload_type >));
+
+        // The i-th variant is wrapped in `Either::Right` for each variant before it.
+        let mut sum_expr = quote!(Either::Left(Field::from( #payload_expr )));
+        let mut sum_pattern = quote!(Either::Left( #payload_pattern ));
+
+        for _ in 0..i {
+            sum_expr = quote!(Either::Right( #sum_expr ));
+            sum_pattern = quote!(Either::Right( #sum_pattern ));
+        }
+
+        into_variants_arms.push(quote!( #variant_pattern => #sum_expr, ));
+        from_variants_arms.push(quote!( #sum_pattern => #variant_expr, ));
+    }
+
+    let variants_type = variant_types
+        .iter()
+        .rfold(quote!(Void), |tail, head| quote!(Either< #head, #tail >));
+
+    // The remaining sum after all variants is `Void`, which can never be constructed.
+    let void_pattern = item_enum
+        .variants
+        .iter()
+        .fold(quote!(void), |pattern, _| quote!(Either::Right( #pattern )));
+
+    let has_variants_impl: ItemImpl = parse_quote! {
+        impl #impl_generics HasVariants for #enum_ident #ty_generics
+        #where_clause
+        {
+            type Variants = #variants_type;
+        }
+    };
+
+    let into_variants_impl: ItemImpl = parse_quote! {
+        impl #impl_generics IntoVariants for #enum_ident #ty_generics
+        #where_clause
+        {
+            fn into_variants(self) -> Self::Variants {
+                match self {
+                    #( #into_variants_arms )*
+                }
+            }
+        }
+    };
+
+    let from_variants_impl: ItemImpl = parse_quote! {
+        impl #impl_generics FromVariants for #enum_ident #ty_generics
+        #where_clause
+        {
+            fn from_variants(variants: Self::Variants) -> Self {
+                match variants {
+                    #( #from_variants_arms )*
+                    #void_pattern => match void {},
+                }
+            }
+        }
+    };
+
+    vec![has_variants_impl, into_variants_impl, from_variants_impl]
+}
+
+/// Derive the payload of a variant based on the shape of its fields.
+fn derive_variant_payload(variant: &Variant) -> VariantPayload {
+    let variant_ident = &variant.ident;
+
+    let field_vars: Vec<Ident> = (0..variant.fields.len())
+        .map(|i| Ident::new(&format!("field_{i}"), Span::call_site()))
+        .collect();
+
+    match &variant.fields {
+        Fields::Unit => VariantPayload {
+            payload_type: parse_quote!(()),
+            variant_pattern: quote!(Self:: #variant_ident),
+            payload_expr: quote!(()),
+            payload_pattern: quote!(_),
+            variant_expr: quote!(Self:: #variant_ident),
+        },
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            let field_type = &fields.unnamed[0].ty;
+
+            VariantPayload {
+                payload_type: parse_quote!( #field_type ),
+                variant_pattern: quote!(Self:: #variant_ident ( field_0 )),
+                payload_expr: quote!(field_0),
+                payload_pattern: quote!(field_0),
+                variant_expr: quote!(Self:: #variant_ident ( field_0.value )),
+            }
+        }
+        Fields::Unnamed(fields) => {
+            let field_types = fields.unnamed.iter().map(|field| &field.ty);
+
+            VariantPayload {
+                payload_type: parse_quote!( ( #( #field_types ),* ) ),
+                variant_pattern: quote!(Self:: #variant_ident ( #( #field_vars ),* )),
+                payload_expr: quote!( ( #( #field_vars ),* ) ),
+                payload_pattern: quote!(Field { value: ( #( #field_vars ),* ), .. }),
+                variant_expr: quote!(Self:: #variant_ident ( #( #field_vars ),* )),
+            }
+        }
+        Fields::Named(fields) => {
+            let mut payload_type: Type = parse_quote!(Nil);
+            let mut payload_expr = quote!(Nil);
+            let mut fields_pattern = quote!(Nil);
+
+            for (field, field_var) in fields.named.iter().zip(&field_vars).rev() {
+                let field_tag = symbol_from_string(&field.ident.as_ref().unwrap().to_string());
+                let field_type = &field.ty;
+
+                payload_type =
+                    parse_quote!(Cons< Field< #field_tag, #field_type >, #payload_type >);
+                payload_expr = quote!(Cons( Field::from( #field_var ), #payload_expr ));
+                fields_pattern = quote!(Cons( #field_var, #fields_pattern ));
+            }
+
+            let field_idents: Vec<&Ident> = fields
+                .named
+                .iter()
+                .map(|field| field.ident.as_ref().unwrap())
+                .collect();
+
+            VariantPayload {
+                payload_type,
+                variant_pattern: quote!(Self:: #variant_ident { #( #field_idents: #field_vars ),* }),
+                payload_expr,
+                payload_pattern: quote!(Field { value: #fields_pattern, .. }),
+                variant_expr: quote!(Self:: #variant_ident { #( #field_idents: #field_vars.value ),* }),
+            }
+        }
+    }
+}
+
+/// Derive the structural representation traits for an enum.
+///
+/// # Arguments
+///
+/// * `input` - Token stream containing the enum definition
+///
+/// # Returns
+///
+/// Token stream containing the generated trait implementations, or an error
+/// if the input is not an enum.
+pub fn derive_has_variants(input: TokenStream) -> syn::Result<TokenStream> {
+    let item_enum = match syn::parse2(input)? {
+        Item::Enum(item_enum) => item_enum,
+        Item::Struct(item_struct) => {
+            return Err(Error::new_spanned(
+                item_struct.struct_token,
+                "`HasVariants` cannot be derived for structs; derive `HasFields` instead",
+            ))
+        }
+        item => {
+            return Err(Error::new_spanned(
+                item,
+                "`HasVariants` can only be derived for enums",
+            ))
+        }
+    };
+
+    let item_impls = derive_has_variants_impls(&item_enum);
+
+    let mut output = TokenStream::new();
+
+    for item_impl in item_impls {
+        output.extend(item_impl.to_token_stream());
+    }
+
+    Ok(output)
+}
@@ -9,12 +9,19 @@
    # Modules
 
    * [`field`] - Field access trait derivation
+   * [`field_attributes`] - Parsing of the `#[cgp(...)]` field attributes
+   * [`flatten`] - Forwarding of the fields of flattened fields
+   * [`has_fields`] - Structural representation trait derivation
+   * [`has_variants`] - Structural representation trait derivation for enums
    * [`product`] - Product and sum type construction
    * [`symbol`] - Symbol type generation
 
    # Functions
 
    * [`derive_fields`] - Implements field access traits
+   * [`flatten_fields`] - Implements field access traits for flattened fields
+   * [`derive_has_fields`] - Implements structural representation traits
+   * [`derive_has_variants`] - Implements structural representation traits for enums
    * [`make_product_type`] - Creates product type definitions
    * [`make_sum_type`] - Creates sum type definitions
    * [`make_product_expr`] - Generates product type expressions
@@ -33,6 +40,10 @@
 */
 
 pub mod field;
+pub mod field_attributes;
+pub mod flatten;
+pub mod has_fields;
+pub mod has_variants;
 pub mod product;
 pub mod symbol;
 
@@ -40,5 +51,8 @@ pub mod symbol;
 mod tests;
 
 pub use field::derive_fields;
+pub use flatten::flatten_fields;
+pub use has_fields::derive_has_fields;
+pub use has_variants::derive_has_variants;
 pub use product::{make_product_expr, make_product_type, make_sum_type};
 pub use symbol::make_symbol;
@@ -6,10 +6,19 @@
    in the CGP framework.
 */
 
+#[cfg(feature = "compact-symbols")]
+use core::iter;
+
+#[cfg(feature = "compact-symbols")]
+use proc_macro2::Literal;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{parse_quote, LitStr, Type};
 
+/// The number of characters in each `Chars` chunk of a compact symbol.
+#[cfg(feature = "compact-symbols")]
+pub const SYMBOL_CHUNK_SIZE: usize = 8;
+
 /// Convert a string into a type-level symbol.
 ///
 /// This function converts a string into a type-level representation using
@@ -30,6 +39,7 @@ use syn::{parse_quote, LitStr, Type};
 /// let symbol = symbol_from_string("abc");
 /// // Generates: Cons<Char<'a'>, Cons<Char<'b'>, Cons<Char<'c'>, Nil>>>
 /// ```
+#[cfg(not(feature = "compact-symbols"))]
 pub fn symbol_from_string(value: &str) -> Type {
     value
         .chars()
@@ -38,6 +48,41 @@ pub fn symbol_from_string(value: &str) -> Type {
         })
 }
 
+/// Convert a string into a compact type-level symbol.
+///
+/// With the `compact-symbols` feature enabled, the characters of the string are
+/// grouped into `Chars` chunks of [`SYMBOL_CHUNK_SIZE`] characters, with the last
+/// chunk padded with `'\0'`. The chunks are wrapped in `Symbol` together with the
+/// length of the string, so that symbols differing only in trailing `'\0'`s are
+/// still distinct.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let symbol = symbol_from_string("abc");
+/// // Generates: Symbol<3, Cons<Chars<'a', 'b', 'c', '\0', '\0', '\0', '\0', '\0'>, Nil>>
+/// ```
+#[cfg(feature = "compact-symbols")]
+pub fn symbol_from_string(value: &str) -> Type {
+    let chars: Vec<char> = value.chars().collect();
+    let len = Literal::usize_unsuffixed(chars.len());
+
+    let chunks =
+        chars
+            .chunks(SYMBOL_CHUNK_SIZE)
+            .rfold(parse_quote! { Nil }, |tail, chunk| -> Type {
+                let chunk_chars = chunk
+                    .iter()
+                    .copied()
+                    .chain(iter::repeat('\0'))
+                    .take(SYMBOL_CHUNK_SIZE);
+
+                parse_quote!( Cons< Chars< #( #chunk_chars ),* >, #tail > )
+            });
+
+    parse_quote!( Symbol< #len, #chunks > )
+}
+
 /// Generate a symbol type from a string literal in macro input.
 ///
 /// This function parses a string literal from the macro input and converts
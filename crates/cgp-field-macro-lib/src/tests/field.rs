@@ -1,7 +1,9 @@
 use quote::quote;
 
 use crate::field::derive_fields;
+use crate::flatten::flatten_fields;
 use crate::tests::helper::equal::equal_token_stream;
+use crate::tests::helper::symbol::expand_symbols;
 
 #[test]
 fn test_basic_derive_fields() {
@@ -10,49 +12,69 @@ fn test_basic_derive_fields() {
             pub bar: Bar,
             pub baz: Baz,
         }
-    });
+    })
+    .unwrap();
 
-    let expected = quote! {
-        impl HasField<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'r'> , Nil>>>> for Foo {
+    let expected = expand_symbols(quote! {
+        impl HasField<symbol!("bar")> for Foo {
             type Value = Bar;
 
             fn get_field(
                 &self,
-                key: ::core::marker::PhantomData<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'r'> , Nil>>>>,
+                key: ::core::marker::PhantomData<symbol!("bar")>,
             ) -> &Self::Value {
                 &self.bar
             }
         }
 
-        impl HasFieldMut<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'r'> , Nil>>>> for Foo {
+        impl HasFieldMut<symbol!("bar")> for Foo {
             fn get_field_mut(
                 &mut self,
-                key: ::core::marker::PhantomData<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'r'> , Nil>>>>,
+                key: ::core::marker::PhantomData<symbol!("bar")>,
             ) -> &mut Self::Value {
                 &mut self.bar
             }
         }
 
-        impl HasField<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'z'> , Nil>>>> for Foo {
+        impl HasField<symbol!("baz")> for Foo {
             type Value = Baz;
 
             fn get_field(
                 &self,
-                key: ::core::marker::PhantomData<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'z'> , Nil>>>>,
+                key: ::core::marker::PhantomData<symbol!("baz")>,
             ) -> &Self::Value {
                 &self.baz
             }
         }
 
-        impl HasFieldMut<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'z'> , Nil>>>> for Foo {
+        impl HasFieldMut<symbol!("baz")> for Foo {
             fn get_field_mut(
                 &mut self,
-                key: ::core::marker::PhantomData<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'z'> , Nil>>>>,
+                key: ::core::marker::PhantomData<symbol!("baz")>,
             ) -> &mut Self::Value {
                 &mut self.baz
             }
         }
-    };
+
+        #[doc(hidden)]
+        #[allow(non_snake_case, unused_macros)]
+        mod __cgp_fields_Foo {
+            macro_rules! Foo {
+                ( $( $body:tt )* ) => {
+                    flatten_fields! {
+                        { mut symbol!("bar"), mut symbol!("baz"), }
+                        $( $body )*
+                    }
+                };
+            }
+
+            pub(crate) use Foo;
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use __cgp_fields_Foo::Foo;
+    });
 
     assert!(equal_token_stream(&derived, &expected));
 }
@@ -67,10 +89,11 @@ fn test_generic_derive_fields() {
             pub bar: Bar<FooParamA>,
             pub baz: Baz<String>,
         }
-    });
+    })
+    .unwrap();
 
-    let expected = quote! {
-        impl<FooParamA, FooParamB: Clone> HasField<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'r'> , Nil>>>>
+    let expected = expand_symbols(quote! {
+        impl<FooParamA, FooParamB: Clone> HasField<symbol!("bar")>
             for Foo<FooParamA, FooParamB>
         where
             FooParamA: Eq,
@@ -79,26 +102,26 @@ fn test_generic_derive_fields() {
 
             fn get_field(
                 &self,
-                key: ::core::marker::PhantomData<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'r'> , Nil>>>>,
+                key: ::core::marker::PhantomData<symbol!("bar")>,
             ) -> &Self::Value {
                 &self.bar
             }
         }
 
-        impl<FooParamA, FooParamB: Clone> HasFieldMut<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'r'> , Nil>>>>
+        impl<FooParamA, FooParamB: Clone> HasFieldMut<symbol!("bar")>
             for Foo<FooParamA, FooParamB>
         where
             FooParamA: Eq,
         {
             fn get_field_mut(
                 &mut self,
-                key: ::core::marker::PhantomData<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'r'> , Nil>>>>,
+                key: ::core::marker::PhantomData<symbol!("bar")>,
             ) -> &mut Self::Value {
                 &mut self.bar
             }
         }
 
-        impl<FooParamA, FooParamB: Clone> HasField<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'z'> , Nil>>>>
+        impl<FooParamA, FooParamB: Clone> HasField<symbol!("baz")>
             for Foo<FooParamA, FooParamB>
         where
             FooParamA: Eq,
@@ -107,25 +130,389 @@ fn test_generic_derive_fields() {
 
             fn get_field(
                 &self,
-                key: ::core::marker::PhantomData<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'z'> , Nil>>>>,
+                key: ::core::marker::PhantomData<symbol!("baz")>,
             ) -> &Self::Value {
                 &self.baz
             }
         }
 
-        impl<FooParamA, FooParamB: Clone> HasFieldMut<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'z'> , Nil>>>>
+        impl<FooParamA, FooParamB: Clone> HasFieldMut<symbol!("baz")>
             for Foo<FooParamA, FooParamB>
         where
             FooParamA: Eq,
         {
             fn get_field_mut(
                 &mut self,
-                key: ::core::marker::PhantomData<Cons<Char<'b'>, Cons<Char<'a'>, Cons<Char<'z'> , Nil>>>>,
+                key: ::core::marker::PhantomData<symbol!("baz")>,
             ) -> &mut Self::Value {
                 &mut self.baz
             }
         }
-    };
+
+        #[doc(hidden)]
+        #[allow(non_snake_case, unused_macros)]
+        mod __cgp_fields_Foo {
+            macro_rules! Foo {
+                ( $( $body:tt )* ) => {
+                    flatten_fields! {
+                        { mut symbol!("bar"), mut symbol!("baz"), }
+                        $( $body )*
+                    }
+                };
+            }
+
+            pub(crate) use Foo;
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use __cgp_fields_Foo::Foo;
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_tuple_struct_derive_fields() {
+    let derived = derive_fields(quote! {
+        pub struct Foo<Bar>(pub Bar, pub Baz);
+    })
+    .unwrap();
+
+    let expected = expand_symbols(quote! {
+        impl<Bar> HasField<Index<0>> for Foo<Bar> {
+            type Value = Bar;
+
+            fn get_field(
+                &self,
+                key: ::core::marker::PhantomData<Index<0>>,
+            ) -> &Self::Value {
+                &self.0
+            }
+        }
+
+        impl<Bar> HasFieldMut<Index<0>> for Foo<Bar> {
+            fn get_field_mut(
+                &mut self,
+                key: ::core::marker::PhantomData<Index<0>>,
+            ) -> &mut Self::Value {
+                &mut self.0
+            }
+        }
+
+        impl<Bar> HasField<Index<1>> for Foo<Bar> {
+            type Value = Baz;
+
+            fn get_field(
+                &self,
+                key: ::core::marker::PhantomData<Index<1>>,
+            ) -> &Self::Value {
+                &self.1
+            }
+        }
+
+        impl<Bar> HasFieldMut<Index<1>> for Foo<Bar> {
+            fn get_field_mut(
+                &mut self,
+                key: ::core::marker::PhantomData<Index<1>>,
+            ) -> &mut Self::Value {
+                &mut self.1
+            }
+        }
+
+        #[doc(hidden)]
+        #[allow(non_snake_case, unused_macros)]
+        mod __cgp_fields_Foo {
+            macro_rules! Foo {
+                ( $( $body:tt )* ) => {
+                    flatten_fields! {
+                        { mut Index<0>, mut Index<1>, }
+                        $( $body )*
+                    }
+                };
+            }
+
+            pub(crate) use Foo;
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use __cgp_fields_Foo::Foo;
+    });
 
     assert!(equal_token_stream(&derived, &expected));
 }
+
+#[test]
+fn test_derive_fields_rejects_enum() {
+    let result = derive_fields(quote! {
+        pub enum Foo {
+            Bar(Bar),
+            Baz { baz: Baz },
+        }
+    });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_derive_fields_with_attributes() {
+    let derived = derive_fields(quote! {
+        pub struct Foo {
+            #[cgp(rename = "a")]
+            pub bar: Bar,
+            #[cgp(skip)]
+            pub baz: Baz,
+            #[cgp(readonly)]
+            pub b: Qux,
+        }
+    })
+    .unwrap();
+
+    let expected = expand_symbols(quote! {
+        impl HasField<symbol!("a")> for Foo {
+            type Value = Bar;
+
+            fn get_field(
+                &self,
+                key: ::core::marker::PhantomData<symbol!("a")>,
+            ) -> &Self::Value {
+                &self.bar
+            }
+        }
+
+        impl HasFieldMut<symbol!("a")> for Foo {
+            fn get_field_mut(
+                &mut self,
+                key: ::core::marker::PhantomData<symbol!("a")>,
+            ) -> &mut Self::Value {
+                &mut self.bar
+            }
+        }
+
+        impl HasField<symbol!("b")> for Foo {
+            type Value = Qux;
+
+            fn get_field(
+                &self,
+                key: ::core::marker::PhantomData<symbol!("b")>,
+            ) -> &Self::Value {
+                &self.b
+            }
+        }
+
+        #[doc(hidden)]
+        #[allow(non_snake_case, unused_macros)]
+        mod __cgp_fields_Foo {
+            macro_rules! Foo {
+                ( $( $body:tt )* ) => {
+                    flatten_fields! {
+                        { mut symbol!("a"), symbol!("b"), }
+                        $( $body )*
+                    }
+                };
+            }
+
+            pub(crate) use Foo;
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use __cgp_fields_Foo::Foo;
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_fields_with_flatten() {
+    let derived = derive_fields(quote! {
+        pub struct Foo<T> {
+            pub name: String,
+            #[cgp(flatten)]
+            pub bar: Bar<T>,
+            #[cgp(flatten, readonly)]
+            pub pair: (u8, bool),
+        }
+    })
+    .unwrap();
+
+    let expected = expand_symbols(quote! {
+        impl<T> HasField<symbol!("name")> for Foo<T> {
+            type Value = String;
+
+            fn get_field(
+                &self,
+                key: ::core::marker::PhantomData<symbol!("name")>,
+            ) -> &Self::Value {
+                &self.name
+            }
+        }
+
+        impl<T> HasFieldMut<symbol!("name")> for Foo<T> {
+            fn get_field_mut(
+                &mut self,
+                key: ::core::marker::PhantomData<symbol!("name")>,
+            ) -> &mut Self::Value {
+                &mut self.name
+            }
+        }
+
+        flatten_fields! {
+            [ 2 => Index<0>, 2 => Index<1> ]
+            [ 1 => mut Bar ]
+            pub struct Foo<T> {
+                pub name: String,
+                #[cgp(flatten)]
+                pub bar: Bar<T>,
+                #[cgp(flatten, readonly)]
+                pub pair: (u8, bool),
+            }
+        }
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_flatten_fields_invokes_callback() {
+    let derived = flatten_fields(expand_symbols(quote! {
+        { mut symbol!("host"), symbol!("port"), }
+        { 1 mut }
+        [ 2 => Index<0> ]
+        [ 0 => mut config::Limits ]
+        pub struct Foo {
+            #[cgp(flatten)]
+            pub limits: config::Limits,
+            #[cgp(flatten)]
+            pub config: Config,
+            #[cgp(flatten, readonly)]
+            pub pair: (u8,),
+        }
+    }))
+    .unwrap();
+
+    let expected = expand_symbols(quote! {
+        config::Limits! {
+            { 0 mut }
+            [ 2 => Index<0>, 1 => mut symbol!("host"), 1 => symbol!("port") ]
+            []
+            pub struct Foo {
+                #[cgp(flatten)]
+                pub limits: config::Limits,
+                #[cgp(flatten)]
+                pub config: Config,
+                #[cgp(flatten, readonly)]
+                pub pair: (u8,),
+            }
+        }
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_flatten_fields_forwards_tags() {
+    let derived = flatten_fields(expand_symbols(quote! {
+        { mut symbol!("host"), symbol!("port"), }
+        { 1 }
+        []
+        []
+        pub struct Foo<T> {
+            pub name: T,
+            #[cgp(flatten, readonly)]
+            pub config: Config<T>,
+        }
+    }))
+    .unwrap();
+
+    let expected = expand_symbols(quote! {
+        impl<T> HasField<symbol!("host")> for Foo<T>
+        where
+            Config<T>: HasField<symbol!("host")>,
+        {
+            type Value = <Config<T> as HasField<symbol!("host")>>::Value;
+
+            fn get_field(
+                &self,
+                key: ::core::marker::PhantomData<symbol!("host")>,
+            ) -> &Self::Value {
+                <Config<T> as HasField<symbol!("host")>>::get_field(&self.config, key)
+            }
+        }
+
+        impl<T> HasField<symbol!("port")> for Foo<T>
+        where
+            Config<T>: HasField<symbol!("port")>,
+        {
+            type Value = <Config<T> as HasField<symbol!("port")>>::Value;
+
+            fn get_field(
+                &self,
+                key: ::core::marker::PhantomData<symbol!("port")>,
+            ) -> &Self::Value {
+                <Config<T> as HasField<symbol!("port")>>::get_field(&self.config, key)
+            }
+        }
+
+        #[doc(hidden)]
+        #[allow(non_snake_case, unused_macros)]
+        mod __cgp_fields_Foo {
+            macro_rules! Foo {
+                ( $( $body:tt )* ) => {
+                    flatten_fields! {
+                        { mut symbol!("name"), symbol!("host"), symbol!("port"), }
+                        $( $body )*
+                    }
+                };
+            }
+
+            pub(crate) use Foo;
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use __cgp_fields_Foo::Foo;
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_fields_rejects_invalid_attributes() {
+    let flatten_generic_field = derive_fields(quote! {
+        pub struct Foo<Bar> {
+            #[cgp(flatten)]
+            pub bar: Bar,
+        }
+    });
+
+    assert!(flatten_generic_field.is_err());
+
+    let flatten_reference_field = derive_fields(quote! {
+        pub struct Foo<'a> {
+            #[cgp(flatten)]
+            pub bar: &'a Bar,
+        }
+    });
+
+    assert!(flatten_reference_field.is_err());
+
+    let unknown_option = derive_fields(quote! {
+        pub struct Foo {
+            #[cgp(hidden)]
+            pub bar: Bar,
+        }
+    });
+
+    assert!(unknown_option.is_err());
+
+    let skip_with_rename = derive_fields(quote! {
+        pub struct Foo {
+            #[cgp(skip, rename = "baz")]
+            pub bar: Bar,
+        }
+    });
+
+    assert!(skip_with_rename.is_err());
+}
@@ -0,0 +1,112 @@
+use quote::quote;
+
+use crate::has_fields::derive_has_fields;
+use crate::tests::helper::equal::equal_token_stream;
+use crate::tests::helper::symbol::expand_symbols;
+
+#[test]
+fn test_basic_derive_has_fields() {
+    let derived = derive_has_fields(quote! {
+        pub struct Foo<T> {
+            pub a: T,
+            pub b: Bar,
+        }
+    })
+    .unwrap();
+
+    let expected = expand_symbols(quote! {
+        impl<T> HasFields for Foo<T> {
+            type Fields = Cons<
+                Field<symbol!("a"), T>,
+                Cons<Field<symbol!("b"), Bar>, Nil>,
+            >;
+        }
+
+        impl<T> IntoFields for Foo<T> {
+            fn into_fields(self) -> Self::Fields {
+                Cons(Field::from(self.a), Cons(Field::from(self.b), Nil))
+            }
+        }
+
+        impl<T> FromFields for Foo<T> {
+            fn from_fields(fields: Self::Fields) -> Self {
+                let Cons(field_0, Cons(field_1, Nil)) = fields;
+
+                Self {
+                    a: field_0.value,
+                    b: field_1.value
+                }
+            }
+        }
+
+        impl<T> ToFieldsRef for Foo<T> {
+            type FieldsRef<'__fields> = Cons<
+                Field<symbol!("a"), &'__fields T>,
+                Cons<Field<symbol!("b"), &'__fields Bar>, Nil>,
+            >
+            where
+                Self: '__fields;
+
+            fn to_fields_ref(&self) -> Self::FieldsRef<'_> {
+                Cons(Field::from(&self.a), Cons(Field::from(&self.b), Nil))
+            }
+        }
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_tuple_struct_derive_has_fields() {
+    let derived = derive_has_fields(quote! {
+        pub struct Foo(pub Bar);
+    })
+    .unwrap();
+
+    let expected = expand_symbols(quote! {
+        impl HasFields for Foo {
+            type Fields = Cons<Field<Index<0>, Bar>, Nil>;
+        }
+
+        impl IntoFields for Foo {
+            fn into_fields(self) -> Self::Fields {
+                Cons(Field::from(self.0), Nil)
+            }
+        }
+
+        impl FromFields for Foo {
+            fn from_fields(fields: Self::Fields) -> Self {
+                let Cons(field_0, Nil) = fields;
+
+                Self {
+                    0: field_0.value
+                }
+            }
+        }
+
+        impl ToFieldsRef for Foo {
+            type FieldsRef<'__fields> = Cons<Field<Index<0>, &'__fields Bar>, Nil>
+            where
+                Self: '__fields;
+
+            fn to_fields_ref(&self) -> Self::FieldsRef<'_> {
+                Cons(Field::from(&self.0), Nil)
+            }
+        }
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_has_fields_rejects_skipped_fields() {
+    let result = derive_has_fields(quote! {
+        pub struct Foo {
+            pub bar: Bar,
+            #[cgp(skip)]
+            pub baz: Baz,
+        }
+    });
+
+    assert!(result.is_err());
+}
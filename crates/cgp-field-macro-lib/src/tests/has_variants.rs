@@ -0,0 +1,57 @@
+use quote::quote;
+
+use crate::has_variants::derive_has_variants;
+use crate::tests::helper::equal::equal_token_stream;
+use crate::tests::helper::symbol::expand_symbols;
+
+#[test]
+fn test_basic_derive_has_variants() {
+    let derived = derive_has_variants(quote! {
+        pub enum Foo<T> {
+            A(T),
+            B,
+        }
+    })
+    .unwrap();
+
+    let expected = expand_symbols(quote! {
+        impl<T> HasVariants for Foo<T> {
+            type Variants = Either<
+                Field<symbol!("A"), T>,
+                Either<Field<symbol!("B"), ()>, Void>,
+            >;
+        }
+
+        impl<T> IntoVariants for Foo<T> {
+            fn into_variants(self) -> Self::Variants {
+                match self {
+                    Self::A(field_0) => Either::Left(Field::from(field_0)),
+                    Self::B => Either::Right(Either::Left(Field::from(()))),
+                }
+            }
+        }
+
+        impl<T> FromVariants for Foo<T> {
+            fn from_variants(variants: Self::Variants) -> Self {
+                match variants {
+                    Either::Left(field_0) => Self::A(field_0.value),
+                    Either::Right(Either::Left(_)) => Self::B,
+                    Either::Right(Either::Right(void)) => match void {},
+                }
+            }
+        }
+    });
+
+    assert!(equal_token_stream(&derived, &expected));
+}
+
+#[test]
+fn test_derive_has_variants_rejects_struct() {
+    let result = derive_has_variants(quote! {
+        pub struct Foo {
+            pub bar: Bar,
+        }
+    });
+
+    assert!(result.is_err());
+}
@@ -1,2 +1,3 @@
 pub mod equal;
 pub mod format;
+pub mod symbol;
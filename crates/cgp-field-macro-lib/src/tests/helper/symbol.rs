@@ -0,0 +1,43 @@
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::LitStr;
+
+use crate::symbol::symbol_from_string;
+
+/// Replaces every `symbol!("...")` in the expected output with the symbol type
+/// of the enabled encoding, so that the same tests cover both encodings.
+pub fn expand_symbols(stream: TokenStream) -> TokenStream {
+    let mut tokens = stream.into_iter().peekable();
+    let mut output = Vec::new();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident == "symbol" => {
+                let is_bang = matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == '!');
+
+                if !is_bang {
+                    output.push(TokenTree::Ident(ident));
+                    continue;
+                }
+
+                tokens.next();
+
+                let Some(TokenTree::Group(group)) = tokens.next() else {
+                    panic!("expected arguments to `symbol!`");
+                };
+
+                let literal: LitStr = syn::parse2(group.stream()).unwrap();
+
+                output.extend(symbol_from_string(&literal.value()).into_token_stream());
+            }
+            TokenTree::Group(group) => {
+                let mut expanded = Group::new(group.delimiter(), expand_symbols(group.stream()));
+                expanded.set_span(group.span());
+                output.push(TokenTree::Group(expanded));
+            }
+            token => output.push(token),
+        }
+    }
+
+    output.into_iter().collect()
+}
@@ -1,4 +1,6 @@
 pub mod field;
+pub mod has_fields;
+pub mod has_variants;
 pub mod helper;
 pub mod product;
 pub mod symbol;
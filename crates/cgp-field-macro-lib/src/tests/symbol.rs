@@ -3,6 +3,7 @@ use quote::quote;
 use crate::symbol::make_symbol;
 use crate::tests::helper::equal::equal_token_stream;
 
+#[cfg(not(feature = "compact-symbols"))]
 #[test]
 fn test_symbol_macro() {
     let symbol = make_symbol(quote!("hello"));
@@ -28,3 +29,26 @@ fn test_symbol_macro() {
 
     assert!(equal_token_stream(&derived, &expected));
 }
+
+#[cfg(feature = "compact-symbols")]
+#[test]
+fn test_compact_symbol_macro() {
+    let symbol = make_symbol(quote!("hello_world"));
+
+    let derived = quote! {
+        type Symbol = #symbol;
+    };
+
+    let expected = quote! {
+        type Symbol = Symbol<
+            11,
+            Cons<
+                Chars<'h', 'e', 'l', 'l', 'o', '_', 'w', 'o'>,
+                Cons<
+                    Chars<'r', 'l', 'd', '\0', '\0', '\0', '\0', '\0'>,
+                    Nil
+                >>>;
+    };
+
+    assert!(equal_token_stream(&derived, &expected));
+}
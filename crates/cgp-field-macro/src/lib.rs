@@ -10,6 +10,10 @@
 
    * [`macro@HasField`] - Derive macro for implementing field access traits.
      Enables type-safe field access and manipulation.
+   * [`macro@HasFields`] - Derive macro for converting structs to and from
+     a product of their fields.
+   * [`macro@HasVariants`] - Derive macro for converting enums to and from
+     a sum of their variants.
 
    ## Type Construction
 
@@ -93,9 +97,136 @@ use proc_macro::TokenStream;
 /// let person = Person { name: "Alice".into(), age: 30 };
 /// let name = person.get_field(field!(name));
 /// ```
-#[proc_macro_derive(HasField)]
+///
+/// The fields of a tuple struct are accessed using `Index<N>` instead:
+///
+/// ```rust,ignore
+/// #[derive(HasField)]
+/// struct Wrapper(String);
+///
+/// let inner = wrapper.get_field(PhantomData::<Index<0>>);
+/// ```
+///
+/// Fields can be customized with the `#[cgp(...)]` attribute. `rename = "..."`
+/// exposes a field under another name, `skip` omits a field, and `readonly`
+/// omits the `HasFieldMut` implementation of a field:
+///
+/// ```rust,ignore
+/// #[derive(HasField)]
+/// struct App {
+///     #[cgp(rename = "name")]
+///     app_name: String,
+///     #[cgp(readonly)]
+///     version: u32,
+///     #[cgp(skip)]
+///     cache: Cache,
+/// }
+/// ```
+///
+/// `flatten` exposes all fields of a nested struct through the parent, instead
+/// of the field itself. The flattened fields can be combined with other fields,
+/// as long as their tags do not overlap:
+///
+/// ```rust,ignore
+/// #[derive(HasField)]
+/// struct Service {
+///     name: String,
+///     #[cgp(flatten)]
+///     config: Config,
+/// }
+///
+/// // Accesses `service.config.port`
+/// let port = service.get_field(PhantomData::<symbol!("port")>);
+/// ```
+///
+/// The tags of a flattened struct are looked up through a hidden macro that
+/// the `HasField` derive defines next to each struct, with the same name as
+/// the struct. Hence the type of a flattened field must be a struct that
+/// derives `HasField` within the same crate, or a tuple. Fields of a generic
+/// type cannot be flattened.
+///
+/// Deriving `HasField` for an enum or a union is an error.
+#[proc_macro_derive(HasField, attributes(cgp))]
 pub fn derive_fields(item: TokenStream) -> TokenStream {
-    cgp_field_macro_lib::derive_fields(item.into()).into()
+    cgp_field_macro_lib::derive_fields(item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Macro for implementing the field access traits of flattened fields.
+///
+/// This macro is invoked by the code generated by `#[derive(HasField)]`, and
+/// is not meant to be used directly. It resolves the tags of the flattened
+/// fields of a struct through the callback macros defined by the derive, and
+/// then implements `HasField` and `HasFieldMut` for each of the tags, by
+/// forwarding to the flattened field.
+#[proc_macro]
+pub fn flatten_fields(body: TokenStream) -> TokenStream {
+    cgp_field_macro_lib::flatten_fields(body.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro for the structural representation of structs.
+///
+/// This macro implements `HasFields`, `IntoFields`, `FromFields` and
+/// `ToFieldsRef` for a struct, which convert the struct to and from a product
+/// of `Field`s. Each field is tagged with the symbol of its name, or with
+/// `Index<N>` for tuple structs.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[derive(HasFields)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// // Person::Fields = Product![
+/// //     Field<symbol!("name"), String>,
+/// //     Field<symbol!("age"), u32>,
+/// // ]
+/// let fields = person.into_fields();
+/// let person = Person::from_fields(fields);
+/// ```
+///
+/// Like `HasField`, a field can be exposed under another name with
+/// `#[cgp(rename = "...")]`. Skipped and flattened fields are rejected.
+#[proc_macro_derive(HasFields, attributes(cgp))]
+pub fn derive_has_fields(item: TokenStream) -> TokenStream {
+    cgp_field_macro_lib::derive_has_fields(item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro for the structural representation of enums.
+///
+/// This macro implements `HasVariants`, `IntoVariants` and `FromVariants` for
+/// an enum, which convert the enum to and from a sum of `Field`s. Each variant
+/// is tagged with the symbol of its name.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[derive(HasVariants)]
+/// enum Shape {
+///     Circle(f64),
+///     Square(f64),
+/// }
+///
+/// // Shape::Variants = Sum![
+/// //     Field<symbol!("Circle"), f64>,
+/// //     Field<symbol!("Square"), f64>,
+/// // ]
+/// let variants = shape.into_variants();
+/// let shape = Shape::from_variants(variants);
+/// ```
+#[proc_macro_derive(HasVariants)]
+pub fn derive_has_variants(item: TokenStream) -> TokenStream {
+    cgp_field_macro_lib::derive_has_variants(item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// Macro for creating type-safe symbol references.
@@ -113,6 +244,11 @@ pub fn derive_fields(item: TokenStream) -> TokenStream {
 /// // Use symbols for field access
 /// let person_name = person.get_field(name_symbol);
 /// ```
+///
+/// By default, each character of the symbol is encoded as a `Cons<Char<_>, ...>`
+/// layer. With the `compact-symbols` feature enabled, the characters are instead
+/// grouped into chunks of eight, such as `Symbol<4, Cons<Chars<'n', 'a', 'm', 'e',
+/// '\0', '\0', '\0', '\0'>, Nil>>`, which reduces compile times for long field names.
 #[proc_macro]
 pub fn symbol(body: TokenStream) -> TokenStream {
     cgp_field_macro_lib::make_symbol(body.into()).into()
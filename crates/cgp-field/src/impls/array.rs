@@ -0,0 +1,54 @@
+/*!
+   Field access implementations for arrays.
+
+   This module implements [`HasField`] and [`HasFieldMut`] for arrays, with
+   each element accessed by its position through [`Index`]. The index is
+   checked through the [`IsArrayIndex`] bound, so that accessing an index that
+   is out of bounds is rejected when the trait is resolved.
+*/
+
+use core::marker::PhantomData;
+
+use crate::traits::{HasField, HasFieldMut};
+use crate::types::Index;
+
+/// Marker trait implemented for `Index<I>` with all `I < N`, for arrays of
+/// length `N` up to 32.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is out of bounds for an array of length {N}",
+    note = "field access on arrays is supported for arrays of up to 32 elements"
+)]
+pub trait IsArrayIndex<const N: usize> {}
+
+macro_rules! impl_array_indices {
+    ( [ $( $index:literal )* ] $len:literal $( $rest:literal )* ) => {
+        $(
+            impl IsArrayIndex<$len> for Index<$index> {}
+        )*
+
+        impl_array_indices!([ $( $index )* $len ] $( $rest )*);
+    };
+    ( [ $( $index:literal )* ] ) => {};
+}
+
+impl_array_indices!([0] 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32);
+
+impl<T, const N: usize, const I: usize> HasField<Index<I>> for [T; N]
+where
+    Index<I>: IsArrayIndex<N>,
+{
+    type Value = T;
+
+    fn get_field(&self, _tag: PhantomData<Index<I>>) -> &Self::Value {
+        &self[I]
+    }
+}
+
+impl<T, const N: usize, const I: usize> HasFieldMut<Index<I>> for [T; N]
+where
+    Index<I>: IsArrayIndex<N>,
+{
+    fn get_field_mut(&mut self, _tag: PhantomData<Index<I>>) -> &mut Self::Value {
+        &mut self[I]
+    }
+}
@@ -6,4 +6,6 @@
    functionality for field access and manipulation.
 */
 
+pub mod array;
+pub mod tuple;
 pub mod use_field;
@@ -0,0 +1,50 @@
+/*!
+   Field access implementations for tuples.
+
+   This module implements [`HasField`] and [`HasFieldMut`] for tuples of up to
+   12 elements, with each element accessed by its position through [`Index`].
+*/
+
+use core::marker::PhantomData;
+
+use crate::traits::{HasField, HasFieldMut};
+use crate::types::Index;
+
+macro_rules! impl_has_field_for_tuple {
+    ( $params:tt { $( $index:tt => $field:ident ),+ } ) => {
+        $(
+            impl_has_field_for_tuple_element!($params, $index, $field);
+        )+
+    };
+}
+
+macro_rules! impl_has_field_for_tuple_element {
+    ( [ $( $param:ident ),+ ], $index:tt, $field:ident ) => {
+        impl< $( $param ),+ > HasField<Index<$index>> for ( $( $param, )+ ) {
+            type Value = $field;
+
+            fn get_field(&self, _tag: PhantomData<Index<$index>>) -> &Self::Value {
+                &self.$index
+            }
+        }
+
+        impl< $( $param ),+ > HasFieldMut<Index<$index>> for ( $( $param, )+ ) {
+            fn get_field_mut(&mut self, _tag: PhantomData<Index<$index>>) -> &mut Self::Value {
+                &mut self.$index
+            }
+        }
+    };
+}
+
+impl_has_field_for_tuple!([T0] { 0 => T0 });
+impl_has_field_for_tuple!([T0, T1] { 0 => T0, 1 => T1 });
+impl_has_field_for_tuple!([T0, T1, T2] { 0 => T0, 1 => T1, 2 => T2 });
+impl_has_field_for_tuple!([T0, T1, T2, T3] { 0 => T0, 1 => T1, 2 => T2, 3 => T3 });
+impl_has_field_for_tuple!([T0, T1, T2, T3, T4] { 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4 });
+impl_has_field_for_tuple!([T0, T1, T2, T3, T4, T5] { 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5 });
+impl_has_field_for_tuple!([T0, T1, T2, T3, T4, T5, T6] { 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6 });
+impl_has_field_for_tuple!([T0, T1, T2, T3, T4, T5, T6, T7] { 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7 });
+impl_has_field_for_tuple!([T0, T1, T2, T3, T4, T5, T6, T7, T8] { 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7, 8 => T8 });
+impl_has_field_for_tuple!([T0, T1, T2, T3, T4, T5, T6, T7, T8, T9] { 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7, 8 => T8, 9 => T9 });
+impl_has_field_for_tuple!([T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10] { 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7, 8 => T8, 9 => T9, 10 => T10 });
+impl_has_field_for_tuple!([T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11] { 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7, 8 => T8, 9 => T9, 10 => T10, 11 => T11 });
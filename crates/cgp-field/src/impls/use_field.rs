@@ -8,8 +8,8 @@
 
 use core::marker::PhantomData;
 
-use cgp_component::WithProvider;
-use cgp_type::traits::has_type::ProvideType;
+use cgp_component::{cgp_provider, IsProviderFor, WithProvider};
+use cgp_type::traits::has_type::{ProvideType, TypeComponent};
 
 use crate::traits::has_field::{FieldGetter, HasField};
 use crate::traits::has_field_mut::{HasFieldMut, MutFieldGetter};
@@ -50,6 +50,7 @@ pub type WithField<Tag> = WithProvider<UseField<Tag>>;
 /// This implementation allows fields to be accessed as types through
 /// the type provider system. It connects the field access system with
 /// the type system.
+#[cgp_provider(TypeComponent)]
 impl<Context, TypeTag, FieldTag, Field> ProvideType<Context, TypeTag> for UseField<FieldTag>
 where
     Context: HasField<FieldTag, Value = Field>,
@@ -13,11 +13,24 @@
    * [`HasField`] - Trait for types with accessible fields
    * [`HasFieldMut`] - Trait for mutable field access
    * [`MutFieldGetter`] - Trait for mutable field getters
+   * [`HasFields`] - Trait for the structural representation of structs, together
+     with [`IntoFields`], [`FromFields`] and [`ToFieldsRef`]
+   * [`HasVariants`] - Trait for the structural representation of enums, together
+     with [`IntoVariants`] and [`FromVariants`]
+   * [`TakeField`], [`UpdateField`], [`RemoveField`] and [`Concat`] - Traits for
+     operating on products of fields
+   * [`Inject`], [`Extract`], [`Widen`], [`Narrow`] and [`FinalizeExtract`] - Traits
+     for operating on sums of variants
+   * [`StaticFormat`] - Trait for formatting type-level symbols at runtime
 
    # Types
 
    * [`Field`] - Type representing a field accessor
+   * [`UseField`] - Provider that implements components through `HasField`
+   * [`Here`] and [`There`] - Type-level paths for locating fields in a product
    * [`Char`] - Character type for field names
+   * [`Symbol`] and [`Chars`] - Compact encoding of symbols, which is used by
+     `symbol!` when the `compact-symbols` feature is enabled
    * [`Cons`] - Type constructor for heterogeneous lists
    * [`Either`] - Sum type for two alternatives
    * [`Index`] - Type-safe indexing
@@ -28,6 +41,9 @@
 
    The crate re-exports several macros from [`cgp_field_macro`]:
    * `HasField` - Derive macro for field access traits
+   * `HasFields` - Derive macro for the structural representation of structs
+   * `HasVariants` - Derive macro for the structural representation of enums
+   * `flatten_fields` - Macro used by `HasField` to forward the tags of flattened fields
    * `Product` - Macro for defining product types
    * `Sum` - Macro for defining sum types
    * `product` - Macro for product type expressions
@@ -52,10 +68,20 @@
    ```
 */
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod impls;
 pub mod traits;
 pub mod types;
 
-pub use cgp_field_macro::{product, symbol, HasField, Product, Sum};
-pub use traits::{FieldGetter, HasField, HasFieldMut, MutFieldGetter};
-pub use types::{Char, Cons, Either, Field, Index, Nil, Void};
+pub use cgp_field_macro::{
+    flatten_fields, product, symbol, HasField, HasFields, HasVariants, Product, Sum,
+};
+pub use impls::use_field::{UseField, WithField};
+pub use traits::{
+    Concat, Extract, FieldGetter, FinalizeExtract, FromFields, FromVariants, HasField, HasFieldMut,
+    HasFields, HasVariants, Inject, IntoFields, IntoVariants, MutFieldGetter, Narrow, RemoveField,
+    StaticFormat, TakeField, ToFieldsRef, UpdateField, Widen,
+};
+pub use types::{Char, Chars, Cons, Either, Field, Here, Index, Nil, Symbol, There, Void};
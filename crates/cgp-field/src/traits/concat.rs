@@ -0,0 +1,41 @@
+/*!
+   Trait for concatenating products.
+*/
+
+use crate::types::{Cons, Nil};
+
+/// Trait for appending the product `Other` to the end of a product.
+///
+/// # Examples
+///
+/// ```rust
+/// use cgp_field::{product, Concat, Cons, Nil};
+///
+/// let Cons(a, Cons(b, Cons(c, Nil))) = product![1, "two"].concat(product![3.0]);
+///
+/// assert_eq!((a, b, c), (1, "two", 3.0));
+/// ```
+pub trait Concat<Other> {
+    type Output;
+
+    fn concat(self, other: Other) -> Self::Output;
+}
+
+impl<Other> Concat<Other> for Nil {
+    type Output = Other;
+
+    fn concat(self, other: Other) -> Self::Output {
+        other
+    }
+}
+
+impl<Head, Tail, Other> Concat<Other> for Cons<Head, Tail>
+where
+    Tail: Concat<Other>,
+{
+    type Output = Cons<Head, Tail::Output>;
+
+    fn concat(self, other: Other) -> Self::Output {
+        Cons(self.0, self.1.concat(other))
+    }
+}
@@ -0,0 +1,78 @@
+/*!
+   Trait for extracting a variant from a sum.
+*/
+
+use core::marker::PhantomData;
+
+use crate::types::{Either, Field, Here, There};
+
+/// Trait for sums that contain a variant tagged with `Tag`.
+///
+/// Extracting returns the payload of the variant if the sum holds the variant,
+/// or otherwise the remaining sum without the variant. Extracting all variants
+/// one by one leaves a remainder of `Void`, which can be eliminated with
+/// [`FinalizeExtract`](crate::FinalizeExtract).
+///
+/// # Examples
+///
+/// ```rust
+/// use core::marker::PhantomData;
+///
+/// use cgp_field::{Either, Extract, Field, FinalizeExtract, Inject, Sum, Void};
+///
+/// struct NotFound;
+/// struct Timeout;
+///
+/// type Error = Sum![Field<NotFound, String>, Field<Timeout, u64>];
+///
+/// fn describe(error: Error) -> String {
+///     match error.extract(PhantomData::<NotFound>) {
+///         Ok(path) => format!("not found: {path}"),
+///         Err(rest) => match rest.extract(PhantomData::<Timeout>) {
+///             Ok(seconds) => format!("timed out after {seconds}s"),
+///             Err(rest) => rest.finalize_extract(),
+///         },
+///     }
+/// }
+///
+/// assert_eq!(
+///     describe(Error::inject(PhantomData::<Timeout>, 30)),
+///     "timed out after 30s"
+/// );
+/// ```
+pub trait Extract<Tag, Path> {
+    type Value;
+
+    type Remainder;
+
+    fn extract(self, tag: PhantomData<Tag>) -> Result<Self::Value, Self::Remainder>;
+}
+
+impl<Tag, Value, Tail> Extract<Tag, Here> for Either<Field<Tag, Value>, Tail> {
+    type Value = Value;
+
+    type Remainder = Tail;
+
+    fn extract(self, _tag: PhantomData<Tag>) -> Result<Self::Value, Self::Remainder> {
+        match self {
+            Either::Left(field) => Ok(field.value),
+            Either::Right(tail) => Err(tail),
+        }
+    }
+}
+
+impl<Tag, Head, Tail, Path> Extract<Tag, There<Path>> for Either<Head, Tail>
+where
+    Tail: Extract<Tag, Path>,
+{
+    type Value = Tail::Value;
+
+    type Remainder = Either<Head, Tail::Remainder>;
+
+    fn extract(self, tag: PhantomData<Tag>) -> Result<Self::Value, Self::Remainder> {
+        match self {
+            Either::Left(head) => Err(Either::Left(head)),
+            Either::Right(tail) => tail.extract(tag).map_err(Either::Right),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+/*!
+   Trait for eliminating a sum that has no variants left.
+*/
+
+use crate::types::Void;
+
+/// Trait for sums with no variants, which can never hold a value.
+///
+/// This is implemented for `Void`, which is the remainder after all variants
+/// have been extracted from a sum. Finalizing the extraction statically checks
+/// that all variants have been handled, and can produce a value of any type.
+pub trait FinalizeExtract {
+    fn finalize_extract<T>(self) -> T;
+}
+
+impl FinalizeExtract for Void {
+    fn finalize_extract<T>(self) -> T {
+        match self {}
+    }
+}
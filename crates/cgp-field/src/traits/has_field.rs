@@ -3,10 +3,22 @@
 
    This module provides traits for read-only access to fields in the CGP framework.
    It enables type-safe field access through phantom types and trait implementations.
+
+   `HasField` is implemented for references, and with the `alloc` feature for
+   `Box`, `Rc` and `Arc`, by forwarding to the inner type. There is no blanket
+   implementation for all types that implement `Deref`, as it would conflict
+   with the implementations for arrays: the standard library may implement
+   `Deref` for arrays in the future. Other smart pointers and newtypes need to
+   implement `HasField` by forwarding to the inner type themselves.
 */
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
 use core::marker::PhantomData;
-use core::ops::Deref;
 
 use cgp_component::UseContext;
 
@@ -87,19 +99,71 @@ pub trait FieldGetter<Context, Tag> {
     fn get_field(context: &Context, tag: PhantomData<Tag>) -> &Self::Value;
 }
 
-/// Implementation of `HasField` for types that can be dereferenced.
+/// Implementation of `HasField` for shared references.
 ///
-/// This implementation allows field access through dereferencing, enabling
-/// field access on wrapper types that implement `Deref`.
-impl<Context, Tag, Target, Value> HasField<Tag> for Context
+/// This implementation allows field access through a reference to a type
+/// that has the field.
+impl<Context, Tag> HasField<Tag> for &Context
+where
+    Context: HasField<Tag> + ?Sized,
+{
+    type Value = Context::Value;
+
+    fn get_field(&self, tag: PhantomData<Tag>) -> &Self::Value {
+        Context::get_field(self, tag)
+    }
+}
+
+/// Implementation of `HasField` for mutable references.
+impl<Context, Tag> HasField<Tag> for &mut Context
+where
+    Context: HasField<Tag> + ?Sized,
+{
+    type Value = Context::Value;
+
+    fn get_field(&self, tag: PhantomData<Tag>) -> &Self::Value {
+        Context::get_field(self, tag)
+    }
+}
+
+/// Implementation of `HasField` for `Box`.
+#[cfg(feature = "alloc")]
+impl<Context, Tag> HasField<Tag> for Box<Context>
+where
+    Context: HasField<Tag> + ?Sized,
+{
+    type Value = Context::Value;
+
+    fn get_field(&self, tag: PhantomData<Tag>) -> &Self::Value {
+        Context::get_field(self, tag)
+    }
+}
+
+/// Implementation of `HasField` for `Rc`, which allows field access on
+/// shared contexts.
+#[cfg(feature = "alloc")]
+impl<Context, Tag> HasField<Tag> for Rc<Context>
+where
+    Context: HasField<Tag> + ?Sized,
+{
+    type Value = Context::Value;
+
+    fn get_field(&self, tag: PhantomData<Tag>) -> &Self::Value {
+        Context::get_field(self, tag)
+    }
+}
+
+/// Implementation of `HasField` for `Arc`, which allows field access on
+/// shared contexts.
+#[cfg(feature = "alloc")]
+impl<Context, Tag> HasField<Tag> for Arc<Context>
 where
-    Context: Deref<Target = Target>,
-    Target: HasField<Tag, Value = Value> + 'static,
+    Context: HasField<Tag> + ?Sized,
 {
-    type Value = Value;
+    type Value = Context::Value;
 
     fn get_field(&self, tag: PhantomData<Tag>) -> &Self::Value {
-        self.deref().get_field(tag)
+        Context::get_field(self, tag)
     }
 }
 
@@ -5,8 +5,9 @@
    It extends the immutable field access traits with mutable counterparts.
 */
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 use core::marker::PhantomData;
-use core::ops::DerefMut;
 
 use crate::traits::has_field::HasField;
 use crate::FieldGetter;
@@ -39,6 +40,16 @@ use crate::FieldGetter;
 ///     }
 /// }
 /// ```
+///
+/// The `HasField` derive omits this trait for fields marked with
+/// `#[cgp(readonly)]`, so mutable getters cannot be used with these fields.
+/// The diagnostic below is shown where `HasFieldMut` is required directly. When
+/// it is required through a getter trait, the compiler reports the getter trait
+/// instead, and only names the missing `HasFieldMut` implementation.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not have a mutable field with the tag `{Tag}`",
+    note = "fields marked with `#[cgp(readonly)]` cannot be accessed mutably"
+)]
 pub trait HasFieldMut<Tag>: HasField<Tag> {
     fn get_field_mut(&mut self, tag: PhantomData<Tag>) -> &mut Self::Value;
 }
@@ -72,16 +83,26 @@ pub trait MutFieldGetter<Context, Tag>: FieldGetter<Context, Tag> {
     fn get_field_mut(context: &mut Context, tag: PhantomData<Tag>) -> &mut Self::Value;
 }
 
-/// Implementation of `HasFieldMut` for types that can be mutably dereferenced.
+/// Implementation of `HasFieldMut` for mutable references.
 ///
-/// This implementation allows mutable field access through dereferencing,
-/// enabling field modification on wrapper types that implement `DerefMut`.
-impl<Context, Tag, Target, Value> HasFieldMut<Tag> for Context
+/// This implementation allows mutable field access through a mutable
+/// reference to a type that has the field.
+impl<Context, Tag> HasFieldMut<Tag> for &mut Context
+where
+    Context: HasFieldMut<Tag> + ?Sized,
+{
+    fn get_field_mut(&mut self, tag: PhantomData<Tag>) -> &mut Self::Value {
+        Context::get_field_mut(self, tag)
+    }
+}
+
+/// Implementation of `HasFieldMut` for `Box`.
+#[cfg(feature = "alloc")]
+impl<Context, Tag> HasFieldMut<Tag> for Box<Context>
 where
-    Context: DerefMut<Target = Target>,
-    Target: HasFieldMut<Tag, Value = Value> + 'static,
+    Context: HasFieldMut<Tag> + ?Sized,
 {
     fn get_field_mut(&mut self, tag: PhantomData<Tag>) -> &mut Self::Value {
-        self.deref_mut().get_field_mut(tag)
+        Context::get_field_mut(self, tag)
     }
 }
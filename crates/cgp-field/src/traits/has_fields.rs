@@ -0,0 +1,57 @@
+/*!
+   Traits for the structural representation of structs.
+
+   This module provides traits for converting a struct to and from a product of
+   its fields, such that generic code can work on any struct through its fields.
+*/
+
+/// Trait for types that have a structural representation as a product of fields.
+///
+/// The `Fields` type is a product built from `Cons` and `Nil`, with each field
+/// wrapped in a [`Field`](crate::Field) that is tagged with the field name as a
+/// symbol. The fields of tuple structs are tagged with [`Index`](crate::Index).
+///
+/// This trait, together with [`IntoFields`], [`FromFields`] and [`ToFieldsRef`],
+/// is typically implemented with `#[derive(HasFields)]`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use cgp_field::HasFields;
+///
+/// #[derive(HasFields)]
+/// pub struct Person {
+///     pub name: String,
+///     pub age: u8,
+/// }
+///
+/// // Person::Fields = Product![
+/// //     Field<symbol!("name"), String>,
+/// //     Field<symbol!("age"), u8>,
+/// // ]
+/// ```
+pub trait HasFields {
+    type Fields;
+}
+
+/// Trait for converting a value into the product of its fields.
+pub trait IntoFields: HasFields {
+    fn into_fields(self) -> Self::Fields;
+}
+
+/// Trait for constructing a value from the product of its fields.
+pub trait FromFields: HasFields {
+    fn from_fields(fields: Self::Fields) -> Self;
+}
+
+/// Trait for borrowing a value as a product of references to its fields.
+///
+/// The `FieldsRef` type has the same structure as [`HasFields::Fields`], with
+/// each field value replaced by a reference to the field.
+pub trait ToFieldsRef {
+    type FieldsRef<'a>
+    where
+        Self: 'a;
+
+    fn to_fields_ref(&self) -> Self::FieldsRef<'_>;
+}
@@ -0,0 +1,54 @@
+/*!
+   Traits for the structural representation of enums.
+
+   This module provides traits for converting an enum to and from a sum of its
+   variants, such that generic code can work on any enum through its variants.
+*/
+
+/// Trait for types that have a structural representation as a sum of variants.
+///
+/// The `Variants` type is a sum built from `Either` and `Void`, with the payload
+/// of each variant wrapped in a [`Field`](crate::Field) that is tagged with the
+/// variant name as a symbol. The payload of a variant is:
+///
+/// * `()` for unit variants
+/// * the field type for variants with a single unnamed field
+/// * a tuple of the field types for variants with multiple unnamed fields
+/// * a product of `Field`s, in the same way as [`HasFields`](crate::HasFields),
+///   for variants with named fields
+///
+/// This trait, together with [`IntoVariants`] and [`FromVariants`], is typically
+/// implemented with `#[derive(HasVariants)]`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use cgp_field::HasVariants;
+///
+/// #[derive(HasVariants)]
+/// pub enum Shape {
+///     Circle(f64),
+///     Rectangle { width: f64, height: f64 },
+/// }
+///
+/// // Shape::Variants = Sum![
+/// //     Field<symbol!("Circle"), f64>,
+/// //     Field<symbol!("Rectangle"), Product![
+/// //         Field<symbol!("width"), f64>,
+/// //         Field<symbol!("height"), f64>,
+/// //     ]>,
+/// // ]
+/// ```
+pub trait HasVariants {
+    type Variants;
+}
+
+/// Trait for converting a value into the sum of its variants.
+pub trait IntoVariants: HasVariants {
+    fn into_variants(self) -> Self::Variants;
+}
+
+/// Trait for constructing a value from the sum of its variants.
+pub trait FromVariants: HasVariants {
+    fn from_variants(variants: Self::Variants) -> Self;
+}
@@ -0,0 +1,48 @@
+/*!
+   Trait for injecting a variant into a sum.
+*/
+
+use core::marker::PhantomData;
+
+use crate::types::{Either, Field, Here, There};
+
+/// Trait for sums that contain a variant tagged with `Tag`, with a payload of
+/// type `Value`.
+///
+/// Injecting constructs the sum from the payload of the variant. The `Path`
+/// parameter is inferred, and locates the variant within the sum.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::marker::PhantomData;
+///
+/// use cgp_field::{Either, Field, Inject, Sum, Void};
+///
+/// struct NotFound;
+/// struct Timeout;
+///
+/// type Error = Sum![Field<NotFound, String>, Field<Timeout, u64>];
+///
+/// let error = Error::inject(PhantomData::<Timeout>, 30);
+///
+/// assert!(matches!(error, Either::Right(Either::Left(Field { value: 30, .. }))));
+/// ```
+pub trait Inject<Tag, Value, Path> {
+    fn inject(tag: PhantomData<Tag>, value: Value) -> Self;
+}
+
+impl<Tag, Value, Tail> Inject<Tag, Value, Here> for Either<Field<Tag, Value>, Tail> {
+    fn inject(_tag: PhantomData<Tag>, value: Value) -> Self {
+        Either::Left(Field::from(value))
+    }
+}
+
+impl<Tag, Value, Head, Tail, Path> Inject<Tag, Value, There<Path>> for Either<Head, Tail>
+where
+    Tail: Inject<Tag, Value, Path>,
+{
+    fn inject(tag: PhantomData<Tag>, value: Value) -> Self {
+        Either::Right(Tail::inject(tag, value))
+    }
+}
@@ -2,13 +2,46 @@
    Traits for field access and manipulation.
 
    This module provides the core traits for working with fields in the CGP framework:
-   
+
    * [`HasField`] and [`FieldGetter`] for immutable field access
    * [`HasFieldMut`] and [`MutFieldGetter`] for mutable field access
+   * [`HasFields`], [`IntoFields`], [`FromFields`] and [`ToFieldsRef`] for the
+     structural representation of structs
+   * [`HasVariants`], [`IntoVariants`] and [`FromVariants`] for the structural
+     representation of enums
+   * [`TakeField`], [`UpdateField`], [`RemoveField`] and [`Concat`] for operating
+     on products of fields
+   * [`Inject`], [`Extract`], [`Widen`], [`Narrow`] and [`FinalizeExtract`] for
+     operating on sums of variants
+   * [`StaticFormat`] for formatting type-level symbols at runtime
 */
 
+pub mod concat;
+pub mod extract;
+pub mod finalize_extract;
 pub mod has_field;
 pub mod has_field_mut;
+pub mod has_fields;
+pub mod has_variants;
+pub mod inject;
+pub mod narrow;
+pub mod remove_field;
+pub mod static_format;
+pub mod take_field;
+pub mod update_field;
+pub mod widen;
 
+pub use concat::Concat;
+pub use extract::Extract;
+pub use finalize_extract::FinalizeExtract;
 pub use has_field::{FieldGetter, HasField};
 pub use has_field_mut::{HasFieldMut, MutFieldGetter};
+pub use has_fields::{FromFields, HasFields, IntoFields, ToFieldsRef};
+pub use has_variants::{FromVariants, HasVariants, IntoVariants};
+pub use inject::Inject;
+pub use narrow::Narrow;
+pub use remove_field::RemoveField;
+pub use static_format::{StaticFormat, StaticFormatChunks};
+pub use take_field::TakeField;
+pub use update_field::UpdateField;
+pub use widen::Widen;
@@ -0,0 +1,69 @@
+/*!
+   Trait for converting a sum into a sum with fewer variants.
+*/
+
+use core::marker::PhantomData;
+
+use crate::traits::extract::Extract;
+use crate::types::{Cons, Either, Field, Nil, Void};
+
+/// Trait for converting a sum into the sum `Smaller`, which contains a subset
+/// of the variants of the original sum.
+///
+/// Narrowing succeeds if the sum holds one of the variants in `Smaller`, and
+/// otherwise returns the remaining sum without the variants in `Smaller`. The
+/// `Paths` parameter is a product of the paths of each variant of `Smaller`
+/// within the original sum, which is inferred.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::marker::PhantomData;
+///
+/// use cgp_field::{Either, Field, Inject, Narrow, Sum, Void};
+///
+/// struct NotFound;
+/// struct Timeout;
+/// struct Denied;
+///
+/// type AppError = Sum![Field<Denied, ()>, Field<Timeout, u64>, Field<NotFound, String>];
+/// type IoError = Sum![Field<NotFound, String>, Field<Timeout, u64>];
+///
+/// let error = AppError::inject(PhantomData::<Timeout>, 30);
+///
+/// match Narrow::<IoError, _>::narrow(error) {
+///     Ok(io_error) => {
+///         assert!(matches!(io_error, Either::Right(Either::Left(Field { value: 30, .. }))))
+///     }
+///     Err(_) => panic!("expected an IO error"),
+/// }
+/// ```
+pub trait Narrow<Smaller, Paths> {
+    type Remainder;
+
+    fn narrow(self) -> Result<Smaller, Self::Remainder>;
+}
+
+impl<Sum> Narrow<Void, Nil> for Sum {
+    type Remainder = Sum;
+
+    fn narrow(self) -> Result<Void, Self::Remainder> {
+        Err(self)
+    }
+}
+
+impl<Sum, Tag, Value, SmallerTail, Path, Paths>
+    Narrow<Either<Field<Tag, Value>, SmallerTail>, Cons<Path, Paths>> for Sum
+where
+    Sum: Extract<Tag, Path, Value = Value>,
+    Sum::Remainder: Narrow<SmallerTail, Paths>,
+{
+    type Remainder = <Sum::Remainder as Narrow<SmallerTail, Paths>>::Remainder;
+
+    fn narrow(self) -> Result<Either<Field<Tag, Value>, SmallerTail>, Self::Remainder> {
+        match self.extract(PhantomData) {
+            Ok(value) => Ok(Either::Left(Field::from(value))),
+            Err(remainder) => remainder.narrow().map(Either::Right),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+/*!
+   Trait for removing a field from a product.
+*/
+
+use core::marker::PhantomData;
+
+use crate::traits::take_field::TakeField;
+
+/// Trait for removing the field tagged with `Tag` from a product.
+///
+/// This is implemented for all products that implement [`TakeField`], with
+/// the value of the removed field discarded.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::marker::PhantomData;
+///
+/// use cgp_field::{product, Cons, Field, Nil, RemoveField};
+///
+/// struct Name;
+/// struct Age;
+///
+/// let person = product![Field::<Name, _>::from("Alice"), Field::<Age, _>::from(30)];
+///
+/// let Cons(name, Nil) = person.remove_field(PhantomData::<Age>);
+///
+/// assert_eq!(name.value, "Alice");
+/// ```
+pub trait RemoveField<Tag, Path> {
+    type Remainder;
+
+    fn remove_field(self, tag: PhantomData<Tag>) -> Self::Remainder;
+}
+
+impl<Context, Tag, Path> RemoveField<Tag, Path> for Context
+where
+    Context: TakeField<Tag, Path>,
+{
+    type Remainder = Context::Remainder;
+
+    fn remove_field(self, tag: PhantomData<Tag>) -> Self::Remainder {
+        self.take_field(tag).1
+    }
+}
@@ -0,0 +1,117 @@
+/*!
+   Trait for formatting type-level symbols at runtime.
+*/
+
+use core::fmt::{self, Formatter, Write};
+
+use crate::types::{Char, Chars, Cons, Index, Nil, Symbol};
+
+/// Trait for formatting a type-level value, such as a symbol, without
+/// requiring a value of the type.
+///
+/// This is implemented recursively for the symbol types produced by `symbol!`,
+/// including the compact [`Symbol`] encoding, and for `Index` tags. As a
+/// result, generic code that works with the tags of `HasField` can recover the
+/// field names at runtime, such as for logging or error messages. Since it
+/// writes to a [`Formatter`], it is available in `no_std` environments.
+///
+/// Symbol types also implement `Display` through this trait, so that the name
+/// can be obtained as a `String` with `to_string`.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::fmt::{self, Display, Formatter};
+/// use core::marker::PhantomData;
+///
+/// // `symbol!` expands to the types of the enabled symbol encoding.
+/// use cgp_field::*;
+///
+/// struct FieldName<Tag>(PhantomData<Tag>);
+///
+/// impl<Tag: StaticFormat> Display for FieldName<Tag> {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         Tag::fmt(f)
+///     }
+/// }
+///
+/// assert_eq!(FieldName::<symbol!("name")>(PhantomData).to_string(), "name");
+/// assert_eq!(<symbol!("age")>::default().to_string(), "age");
+/// ```
+pub trait StaticFormat {
+    fn fmt(f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+impl StaticFormat for Nil {
+    fn fmt(_f: &mut Formatter<'_>) -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl<const CHAR: char, Tail> StaticFormat for Cons<Char<CHAR>, Tail>
+where
+    Tail: StaticFormat,
+{
+    fn fmt(f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char(CHAR)?;
+        Tail::fmt(f)
+    }
+}
+
+impl<const CHAR: char> StaticFormat for Char<CHAR> {
+    fn fmt(f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char(CHAR)
+    }
+}
+
+impl<const I: usize> StaticFormat for Index<I> {
+    fn fmt(f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{I}")
+    }
+}
+
+impl<const LEN: usize, Chunks> StaticFormat for Symbol<LEN, Chunks>
+where
+    Chunks: StaticFormatChunks,
+{
+    fn fmt(f: &mut Formatter<'_>) -> fmt::Result {
+        Chunks::fmt_chunks(f, LEN)
+    }
+}
+
+/// Helper trait for formatting the first `len` characters of the `Chars`
+/// chunks of a compact [`Symbol`].
+pub trait StaticFormatChunks {
+    fn fmt_chunks(f: &mut Formatter<'_>, len: usize) -> fmt::Result;
+}
+
+impl StaticFormatChunks for Nil {
+    fn fmt_chunks(_f: &mut Formatter<'_>, _len: usize) -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl<
+        const C0: char,
+        const C1: char,
+        const C2: char,
+        const C3: char,
+        const C4: char,
+        const C5: char,
+        const C6: char,
+        const C7: char,
+        Tail,
+    > StaticFormatChunks for Cons<Chars<C0, C1, C2, C3, C4, C5, C6, C7>, Tail>
+where
+    Tail: StaticFormatChunks,
+{
+    fn fmt_chunks(f: &mut Formatter<'_>, len: usize) -> fmt::Result {
+        let chars = [C0, C1, C2, C3, C4, C5, C6, C7];
+
+        for c in chars.into_iter().take(len) {
+            f.write_char(c)?;
+        }
+
+        Tail::fmt_chunks(f, len.saturating_sub(chars.len()))
+    }
+}
@@ -0,0 +1,64 @@
+/*!
+   Trait for taking a field out of a product.
+*/
+
+use core::marker::PhantomData;
+
+use crate::types::{Cons, Field, Here, There};
+
+/// Trait for products that contain a field tagged with `Tag`.
+///
+/// Taking the field returns its value, together with the remaining product
+/// without the field. The `Path` parameter is inferred, and locates the field
+/// within the product.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::marker::PhantomData;
+///
+/// use cgp_field::{product, Cons, Field, Nil, TakeField};
+///
+/// struct Name;
+/// struct Age;
+///
+/// let person = product![Field::<Name, _>::from("Alice"), Field::<Age, _>::from(30)];
+///
+/// let (age, rest) = person.take_field(PhantomData::<Age>);
+///
+/// assert_eq!(age, 30);
+/// let Cons(name, Nil) = rest;
+/// assert_eq!(name.value, "Alice");
+/// ```
+pub trait TakeField<Tag, Path> {
+    type Value;
+
+    type Remainder;
+
+    fn take_field(self, tag: PhantomData<Tag>) -> (Self::Value, Self::Remainder);
+}
+
+impl<Tag, Value, Tail> TakeField<Tag, Here> for Cons<Field<Tag, Value>, Tail> {
+    type Value = Value;
+
+    type Remainder = Tail;
+
+    fn take_field(self, _tag: PhantomData<Tag>) -> (Self::Value, Self::Remainder) {
+        (self.0.value, self.1)
+    }
+}
+
+impl<Tag, Head, Tail, Path> TakeField<Tag, There<Path>> for Cons<Head, Tail>
+where
+    Tail: TakeField<Tag, Path>,
+{
+    type Value = Tail::Value;
+
+    type Remainder = Cons<Head, Tail::Remainder>;
+
+    fn take_field(self, tag: PhantomData<Tag>) -> (Self::Value, Self::Remainder) {
+        let (value, remainder) = self.1.take_field(tag);
+
+        (value, Cons(self.0, remainder))
+    }
+}
@@ -0,0 +1,64 @@
+/*!
+   Trait for updating a field in a product.
+*/
+
+use core::marker::PhantomData;
+
+use crate::types::{Cons, Field, Here, There};
+
+/// Trait for replacing the value of the field tagged with `Tag` in a product.
+///
+/// The new value can have a different type than the old value, in which case
+/// the type of the field changes in the resulting product. The old value is
+/// returned together with the updated product.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::marker::PhantomData;
+///
+/// use cgp_field::{product, Cons, Field, Nil, UpdateField};
+///
+/// struct Name;
+/// struct Age;
+///
+/// let person = product![Field::<Name, _>::from("Alice"), Field::<Age, _>::from(30)];
+///
+/// let (old_age, person) = person.update_field(PhantomData::<Age>, "thirty-one");
+/// let Cons(_, Cons(age, Nil)) = person;
+///
+/// assert_eq!(old_age, 30);
+/// assert_eq!(age.value, "thirty-one");
+/// ```
+pub trait UpdateField<Tag, Value, Path> {
+    type Value;
+
+    type Output;
+
+    fn update_field(self, tag: PhantomData<Tag>, value: Value) -> (Self::Value, Self::Output);
+}
+
+impl<Tag, Old, New, Tail> UpdateField<Tag, New, Here> for Cons<Field<Tag, Old>, Tail> {
+    type Value = Old;
+
+    type Output = Cons<Field<Tag, New>, Tail>;
+
+    fn update_field(self, _tag: PhantomData<Tag>, value: New) -> (Self::Value, Self::Output) {
+        (self.0.value, Cons(Field::from(value), self.1))
+    }
+}
+
+impl<Tag, New, Head, Tail, Path> UpdateField<Tag, New, There<Path>> for Cons<Head, Tail>
+where
+    Tail: UpdateField<Tag, New, Path>,
+{
+    type Value = Tail::Value;
+
+    type Output = Cons<Head, Tail::Output>;
+
+    fn update_field(self, tag: PhantomData<Tag>, value: New) -> (Self::Value, Self::Output) {
+        let (old, tail) = self.1.update_field(tag, value);
+
+        (old, Cons(self.0, tail))
+    }
+}
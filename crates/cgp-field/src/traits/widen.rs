@@ -0,0 +1,56 @@
+/*!
+   Trait for converting a sum into a sum with more variants.
+*/
+
+use core::marker::PhantomData;
+
+use crate::traits::inject::Inject;
+use crate::types::{Cons, Either, Field, Nil, Void};
+
+/// Trait for converting a sum into the sum `Bigger`, which contains all the
+/// variants of the original sum, and possibly more.
+///
+/// The `Paths` parameter is a product of the paths of each variant within
+/// `Bigger`, which is inferred.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::marker::PhantomData;
+///
+/// use cgp_field::{Either, Field, Inject, Sum, Void, Widen};
+///
+/// struct NotFound;
+/// struct Timeout;
+/// struct Denied;
+///
+/// type IoError = Sum![Field<NotFound, String>, Field<Timeout, u64>];
+/// type AppError = Sum![Field<Denied, ()>, Field<Timeout, u64>, Field<NotFound, String>];
+///
+/// let error: AppError = IoError::inject(PhantomData::<Timeout>, 30).widen();
+///
+/// assert!(matches!(error, Either::Right(Either::Left(Field { value: 30, .. }))));
+/// ```
+pub trait Widen<Bigger, Paths> {
+    fn widen(self) -> Bigger;
+}
+
+impl<Bigger> Widen<Bigger, Nil> for Void {
+    fn widen(self) -> Bigger {
+        match self {}
+    }
+}
+
+impl<Bigger, Tag, Value, Tail, Path, Paths> Widen<Bigger, Cons<Path, Paths>>
+    for Either<Field<Tag, Value>, Tail>
+where
+    Bigger: Inject<Tag, Value, Path>,
+    Tail: Widen<Bigger, Paths>,
+{
+    fn widen(self) -> Bigger {
+        match self {
+            Either::Left(field) => Bigger::inject(PhantomData, field.value),
+            Either::Right(tail) => tail.widen(),
+        }
+    }
+}
@@ -5,6 +5,8 @@
    used as field identifiers in the CGP framework.
 */
 
+use core::fmt::{self, Display, Formatter, Write};
+
 /// A type-level character representation.
 ///
 /// This type allows characters to be used as type parameters, enabling
@@ -24,4 +26,22 @@
 /// type NameField = Char<'n'>;
 /// type AgeField = Char<'a'>;
 /// ```
+///
+/// `Char` implements `Display` by writing the character. It does not implement
+/// `Debug` by itself, so that the symbols built from `Char`s can be debug
+/// formatted as their string, such as `"name"`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Char<const CHAR: char>;
+
+impl<const CHAR: char> Char<CHAR> {
+    /// Creates a value of the character type.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<const CHAR: char> Display for Char<CHAR> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char(CHAR)
+    }
+}
@@ -5,6 +5,9 @@
    data structure with type-safe access through phantom types.
 */
 
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 
 /// A type-safe field representation.
@@ -35,6 +38,55 @@ pub struct Field<Tag, Value> {
     pub phantom: PhantomData<Tag>,
 }
 
+// The standard traits are implemented manually, so that they are only bounded
+// by the field value, and not by the phantom tag.
+
+impl<Tag, Value: Clone> Clone for Field<Tag, Value> {
+    fn clone(&self) -> Self {
+        Self::from(self.value.clone())
+    }
+}
+
+impl<Tag, Value: Copy> Copy for Field<Tag, Value> {}
+
+impl<Tag, Value: Debug> Debug for Field<Tag, Value> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field").field("value", &self.value).finish()
+    }
+}
+
+impl<Tag, Value: PartialEq> PartialEq for Field<Tag, Value> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<Tag, Value: Eq> Eq for Field<Tag, Value> {}
+
+impl<Tag, Value: PartialOrd> PartialOrd for Field<Tag, Value> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<Tag, Value: Ord> Ord for Field<Tag, Value> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<Tag, Value: Hash> Hash for Field<Tag, Value> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<Tag, Value: Default> Default for Field<Tag, Value> {
+    fn default() -> Self {
+        Self::from(Value::default())
+    }
+}
+
 /// Implementation of `From` for converting values into fields.
 ///
 /// This implementation allows easy creation of field values from their
@@ -5,6 +5,8 @@
    used for type-safe array and tuple field access in the CGP framework.
 */
 
+use core::fmt::{self, Debug, Formatter};
+
 /// A type-level index representation.
 ///
 /// This type allows compile-time indices to be used as type parameters,
@@ -17,8 +19,10 @@
 ///
 /// # Examples
 ///
-/// ```rust,ignore
-/// use cgp_field::Index;
+/// ```rust
+/// use core::marker::PhantomData;
+///
+/// use cgp_field::{HasField, Index};
 ///
 /// // Define type-safe indices
 /// type First = Index<0>;
@@ -26,7 +30,27 @@
 ///
 /// // Use with tuples or arrays
 /// let tuple = (1, "hello");
-/// let first: i32 = tuple.get_field(First::new());
-/// let second: &str = tuple.get_field(Second::new());
+/// let first: &i32 = tuple.get_field(PhantomData::<First>);
+/// let second: &&str = tuple.get_field(PhantomData::<Second>);
+///
+/// assert_eq!(*first, 1);
+/// assert_eq!(*second, "hello");
+///
+/// let array = [1, 2, 3];
+/// assert_eq!(*array.get_field(PhantomData::<Index<2>>), 3);
 /// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Index<const I: usize>;
+
+impl<const I: usize> Index<I> {
+    /// Creates a value of the index type.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<const I: usize> Debug for Index<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Index<{I}>")
+    }
+}
@@ -6,8 +6,10 @@
    * [`mod@char`] - Character type definitions for field names
    * [`field`] - Field accessor types
    * [`index`] - Type-safe indexing types
+   * [`path`] - Type-level paths for locating fields in a product
    * [`product`] - Product type (struct) definitions
    * [`sum`] - Sum type (enum) definitions
+   * [`symbol`] - Compact type-level symbols
 
    These types work together to provide a type-safe system for field access
    and manipulation in the CGP framework.
@@ -16,11 +18,15 @@
 pub mod char;
 pub mod field;
 pub mod index;
+pub mod path;
 pub mod product;
 pub mod sum;
+pub mod symbol;
 
 pub use char::*;
 pub use field::*;
 pub use index::*;
+pub use path::*;
 pub use product::*;
 pub use sum::*;
+pub use symbol::*;
@@ -0,0 +1,20 @@
+/*!
+   Type-level paths for locating fields in a product.
+
+   This module provides the [`Here`] and [`There`] types, which describe the
+   position of a field within a product built from `Cons` and `Nil`.
+*/
+
+use core::marker::PhantomData;
+
+/// A type-level path pointing at the head of a product.
+///
+/// Paths are used as an extra type parameter by the product operations such
+/// as [`TakeField`](crate::TakeField). They are inferred by the compiler, and
+/// never need to be written explicitly. Having a distinct path for each
+/// position is what allows the operations to be implemented for both the head
+/// and the tail of a product without overlapping.
+pub struct Here;
+
+/// A type-level path pointing at the position `Path` within the tail of a product.
+pub struct There<Path>(pub PhantomData<Path>);
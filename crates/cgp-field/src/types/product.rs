@@ -6,6 +6,11 @@
    framework.
 */
 
+use core::fmt::{self, Debug, Display, Formatter, Write};
+
+use crate::traits::static_format::StaticFormat;
+use crate::types::char::Char;
+
 /// A cons cell for building heterogeneous lists.
 ///
 /// This type is a building block for constructing heterogeneous lists,
@@ -27,6 +32,34 @@
 ///
 /// let list = Cons(1, Cons("hello".to_string(), Cons(true, Nil)));
 /// ```
+///
+/// Since the symbol types produced by `symbol!` consist of `Cons`, `Char` and
+/// `Nil`, a value of a symbol type can be constructed with `Default`:
+///
+/// ```rust,ignore
+/// let name = <symbol!("name")>::default();
+/// ```
+///
+/// Products implement the standard traits structurally, while symbol types
+/// are displayed and debug formatted as their name through [`StaticFormat`]:
+///
+/// ```rust
+/// // `symbol!` expands to the types of the enabled symbol encoding.
+/// use cgp_field::*;
+///
+/// assert_eq!(<symbol!("name")>::default().to_string(), "name");
+/// assert_eq!(format!("{:?}", <symbol!("name")>::default()), "\"name\"");
+///
+/// let person: Product![Field<symbol!("name"), &str>, Field<symbol!("age"), u8>] =
+///     product![Field::from("Alice"), Field::from(32)];
+///
+/// assert_eq!(person.clone(), person);
+/// assert_eq!(
+///     format!("{person:?}"),
+///     "Cons(Field { value: \"Alice\" }, Cons(Field { value: 32 }, Nil))"
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Cons<Head, Tail>(pub Head, pub Tail);
 
 /// Empty type representing the end of a heterogeneous list.
@@ -47,4 +80,37 @@ pub struct Cons<Head, Tail>(pub Head, pub Tail);
 /// let empty = Nil;
 /// let singleton = Cons(42, Nil);
 /// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Nil;
+
+impl<const CHAR: char, Tail> Display for Cons<Char<CHAR>, Tail>
+where
+    Tail: StaticFormat,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        <Self as StaticFormat>::fmt(f)
+    }
+}
+
+// `Debug` is implemented manually, as it does not overlap with the symbol
+// implementation below only because `Char` does not implement `Debug`.
+impl<Head, Tail> Debug for Cons<Head, Tail>
+where
+    Head: Debug,
+    Tail: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Cons").field(&self.0).field(&self.1).finish()
+    }
+}
+
+impl<const CHAR: char, Tail> Debug for Cons<Char<CHAR>, Tail>
+where
+    Tail: StaticFormat,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        <Self as StaticFormat>::fmt(f)?;
+        f.write_char('"')
+    }
+}
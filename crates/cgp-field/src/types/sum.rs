@@ -6,6 +6,9 @@
    type.
 */
 
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
 /// A binary sum type representing one of two possible types.
 ///
 /// This type is similar to Rust's `Result` type but without the success/error
@@ -33,6 +36,7 @@
 ///     Either::Right(n) => println!("Number: {}", n),
 /// }
 /// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Either<Head, Tail> {
     /// The left variant containing a value of type `Head`
     Left(Head),
@@ -61,4 +65,16 @@ pub enum Either<Head, Tail> {
 /// // Use in generic types
 /// type NoRight<T> = Either<T, Void>;
 /// ```
+///
+/// `Void` implements `Error`, so that it can be used as the error type of
+/// operations that can never fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Void {}
+
+impl Display for Void {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {}
+    }
+}
+
+impl Error for Void {}
@@ -0,0 +1,89 @@
+/*!
+   Compact type-level symbols.
+
+   This module provides an alternative representation of type-level symbols,
+   which is produced by `symbol!` when the `compact-symbols` feature is enabled.
+*/
+
+use core::fmt::{self, Debug, Display, Formatter, Write};
+use core::marker::PhantomData;
+
+use crate::traits::static_format::StaticFormat;
+
+/// A compact type-level symbol, consisting of the length of the symbol and
+/// a list of `Chars` chunks.
+///
+/// By default, `symbol!` encodes a string with one `Cons<Char<_>, ...>` layer
+/// per character. With many long field names, this results in large types that
+/// slow down compilation and clutter error messages. When the `compact-symbols`
+/// feature is enabled, `symbol!` instead groups eight characters into each
+/// `Chars` chunk, with the last chunk padded with `'\0'`. The `LEN` parameter
+/// keeps symbols unique even if they only differ in trailing `'\0'`s.
+///
+/// # Type Parameters
+///
+/// * `LEN` - The number of characters in the symbol
+/// * `Chunks` - A `Cons` list of `Chars` chunks
+///
+/// # Examples
+///
+/// ```rust
+/// use cgp_field::{Chars, Cons, Nil, Symbol};
+///
+/// // The compact encoding of "hello_world"
+/// type HelloWorld = Symbol<
+///     11,
+///     Cons<
+///         Chars<'h', 'e', 'l', 'l', 'o', '_', 'w', 'o'>,
+///         Cons<Chars<'r', 'l', 'd', '\0', '\0', '\0', '\0', '\0'>, Nil>,
+///     >,
+/// >;
+///
+/// assert_eq!(HelloWorld::new().to_string(), "hello_world");
+/// assert_eq!(format!("{:?}", HelloWorld::new()), "\"hello_world\"");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Symbol<const LEN: usize, Chunks>(pub PhantomData<Chunks>);
+
+/// A chunk of eight characters in a compact [`Symbol`].
+///
+/// `Chars` does not implement `Debug` by itself, as it is only meaningful as
+/// part of a `Symbol`, which is debug formatted as its string.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Chars<
+    const C0: char,
+    const C1: char,
+    const C2: char,
+    const C3: char,
+    const C4: char,
+    const C5: char,
+    const C6: char,
+    const C7: char,
+>;
+
+impl<const LEN: usize, Chunks> Symbol<LEN, Chunks> {
+    /// Creates a value of the symbol type.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<const LEN: usize, Chunks> Debug for Symbol<LEN, Chunks>
+where
+    Self: StaticFormat,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        <Self as StaticFormat>::fmt(f)?;
+        f.write_char('"')
+    }
+}
+
+impl<const LEN: usize, Chunks> Display for Symbol<LEN, Chunks>
+where
+    Self: StaticFormat,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        <Self as StaticFormat>::fmt(f)
+    }
+}
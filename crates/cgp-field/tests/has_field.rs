@@ -0,0 +1,98 @@
+use core::marker::PhantomData;
+
+use cgp_field::{HasField, HasFieldMut, Index};
+
+#[test]
+fn test_tuple_fields() {
+    let mut pair = (1u8, "hello");
+
+    assert_eq!(*pair.get_field(PhantomData::<Index<0>>), 1);
+    assert_eq!(*pair.get_field(PhantomData::<Index<1>>), "hello");
+
+    *pair.get_field_mut(PhantomData::<Index<0>>) += 1;
+    *pair.get_field_mut(PhantomData::<Index<1>>) = "world";
+
+    assert_eq!(pair, (2, "world"));
+}
+
+#[test]
+fn test_single_element_tuple_field() {
+    let single = (42u64,);
+
+    assert_eq!(*single.get_field(PhantomData::<Index<0>>), 42);
+}
+
+#[test]
+fn test_largest_tuple_fields() {
+    let mut tuple = (
+        0u8, 1u16, 2u32, 3u64, 4i8, 5i16, 6i32, 7i64, 8usize, 9isize, 'a', "b",
+    );
+
+    assert_eq!(*tuple.get_field(PhantomData::<Index<0>>), 0);
+    assert_eq!(*tuple.get_field(PhantomData::<Index<5>>), 5);
+    assert_eq!(*tuple.get_field(PhantomData::<Index<10>>), 'a');
+    assert_eq!(*tuple.get_field(PhantomData::<Index<11>>), "b");
+
+    *tuple.get_field_mut(PhantomData::<Index<11>>) = "c";
+
+    assert_eq!(tuple.11, "c");
+}
+
+#[test]
+fn test_tuple_field_value_types() {
+    fn value_of<Context, Tag>(context: &Context, tag: PhantomData<Tag>) -> &Context::Value
+    where
+        Context: HasField<Tag>,
+    {
+        context.get_field(tag)
+    }
+
+    let tuple = (String::from("name"), vec![1, 2, 3]);
+
+    let name: &String = value_of(&tuple, PhantomData::<Index<0>>);
+    let values: &Vec<i32> = value_of(&tuple, PhantomData::<Index<1>>);
+
+    assert_eq!(name, "name");
+    assert_eq!(values, &[1, 2, 3]);
+}
+
+#[test]
+fn test_array_fields() {
+    let mut array = [1, 2, 3];
+
+    assert_eq!(*array.get_field(PhantomData::<Index<0>>), 1);
+    assert_eq!(*array.get_field(PhantomData::<Index<2>>), 3);
+
+    *array.get_field_mut(PhantomData::<Index<1>>) = 20;
+
+    assert_eq!(array, [1, 20, 3]);
+}
+
+#[test]
+fn test_fields_through_pointers() {
+    fn first<Context>(context: &Context) -> u8
+    where
+        Context: HasField<Index<0>, Value = u8>,
+    {
+        *context.get_field(PhantomData)
+    }
+
+    fn set_first<Context>(mut context: Context, value: u8)
+    where
+        Context: HasFieldMut<Index<0>, Value = u8>,
+    {
+        *context.get_field_mut(PhantomData) = value;
+    }
+
+    let mut tuple = (1u8, 2u8);
+
+    assert_eq!(first(&&tuple), 1);
+    assert_eq!(first(&&mut tuple), 1);
+    assert_eq!(first(&Box::new(tuple)), 1);
+    assert_eq!(first(&std::rc::Rc::new(tuple)), 1);
+    assert_eq!(first(&std::sync::Arc::new(tuple)), 1);
+
+    set_first(&mut tuple, 3);
+
+    assert_eq!(tuple, (3, 2));
+}
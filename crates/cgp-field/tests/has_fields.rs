@@ -0,0 +1,88 @@
+use cgp_field::*;
+
+#[derive(Debug, Clone, PartialEq, HasFields)]
+pub struct Person {
+    pub name: String,
+    pub age: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, HasField, HasFields)]
+pub struct Account {
+    #[cgp(rename = "name")]
+    pub account_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, HasFields)]
+pub struct Pair<T>(pub T, pub u32);
+
+#[test]
+fn test_named_struct_fields_round_trip() {
+    let person = Person {
+        name: "Alice".to_owned(),
+        age: 30,
+    };
+
+    let fields: Product![Field<symbol!("name"), String>, Field<symbol!("age"), u8>] =
+        person.clone().into_fields();
+
+    let Cons(name, Cons(age, Nil)) = &fields;
+    assert_eq!(name.value, "Alice");
+    assert_eq!(age.value, 30);
+
+    assert_eq!(Person::from_fields(fields), person);
+}
+
+#[test]
+fn test_named_struct_fields_ref() {
+    let person = Person {
+        name: "Bob".to_owned(),
+        age: 42,
+    };
+
+    let Cons(name, Cons(age, Nil)) = person.to_fields_ref();
+
+    assert_eq!(name.value, "Bob");
+    assert_eq!(*age.value, 42);
+}
+
+#[test]
+fn test_tuple_struct_fields_round_trip() {
+    let pair = Pair('x', 7);
+
+    let fields: Product![Field<Index<0>, char>, Field<Index<1>, u32>] = pair.clone().into_fields();
+
+    let Cons(first, Cons(second, Nil)) = &fields;
+    assert_eq!(first.value, 'x');
+    assert_eq!(second.value, 7);
+
+    assert_eq!(Pair::from_fields(fields), pair);
+}
+
+#[test]
+fn test_fields_from_constructed_product() {
+    let person = Person::from_fields(product![Field::from("Carol".to_owned()), Field::from(25),]);
+
+    assert_eq!(
+        person,
+        Person {
+            name: "Carol".to_owned(),
+            age: 25,
+        }
+    );
+}
+
+#[test]
+fn test_renamed_fields_match_has_field() {
+    let account = Account {
+        account_name: "Dave".to_owned(),
+    };
+
+    assert_eq!(
+        account.get_field(core::marker::PhantomData::<symbol!("name")>),
+        "Dave"
+    );
+
+    let fields: Product![Field<symbol!("name"), String>] = account.clone().into_fields();
+
+    assert_eq!(Account::from_fields(fields), account);
+}
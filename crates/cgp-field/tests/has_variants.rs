@@ -0,0 +1,66 @@
+use cgp_field::*;
+
+#[derive(Debug, Clone, PartialEq, HasVariants)]
+pub enum Shape {
+    Circle(f64),
+    Rectangle { width: f64, height: f64 },
+    Empty,
+}
+
+type ShapeVariants = Sum![
+    Field<symbol!("Circle"), f64>,
+    Field<symbol!("Rectangle"), Product![Field<symbol!("width"), f64>, Field<symbol!("height"), f64>]>,
+    Field<symbol!("Empty"), ()>,
+];
+
+#[test]
+fn test_variants_round_trip() {
+    let shapes = [
+        Shape::Circle(1.5),
+        Shape::Rectangle {
+            width: 2.0,
+            height: 3.0,
+        },
+        Shape::Empty,
+    ];
+
+    for shape in shapes {
+        let variants: ShapeVariants = shape.clone().into_variants();
+
+        assert_eq!(Shape::from_variants(variants), shape);
+    }
+}
+
+#[test]
+fn test_variant_payloads() {
+    match Shape::Circle(1.5).into_variants() {
+        Either::Left(circle) => assert_eq!(circle.value, 1.5),
+        _ => panic!("expected the `Circle` variant"),
+    }
+
+    let rectangle = Shape::Rectangle {
+        width: 2.0,
+        height: 3.0,
+    };
+
+    match rectangle.into_variants() {
+        Either::Right(Either::Left(rectangle)) => {
+            let Cons(width, Cons(height, Nil)) = rectangle.value;
+            assert_eq!(width.value, 2.0);
+            assert_eq!(height.value, 3.0);
+        }
+        _ => panic!("expected the `Rectangle` variant"),
+    }
+
+    assert!(matches!(
+        Shape::Empty.into_variants(),
+        Either::Right(Either::Right(Either::Left(_)))
+    ));
+}
+
+#[test]
+fn test_variants_from_constructed_sum() {
+    let shape = Shape::from_variants(Either::Right(Either::Right(Either::Left(Field::from(())))));
+
+    assert_eq!(shape, Shape::Empty);
+}
@@ -0,0 +1,91 @@
+use core::marker::PhantomData;
+
+use cgp_field::*;
+
+mod inner {
+    use cgp_field::*;
+
+    #[derive(HasField)]
+    pub struct ForeignConfig {
+        pub host: String,
+        pub port: u16,
+        #[cgp(readonly)]
+        pub secure: bool,
+    }
+
+    #[derive(HasField)]
+    pub struct Limits<T> {
+        pub max_connections: T,
+    }
+}
+
+use inner::ForeignConfig;
+
+#[derive(HasField)]
+pub struct App {
+    pub name: String,
+    #[cgp(skip)]
+    pub cache: Vec<u8>,
+    #[cgp(flatten)]
+    pub config: ForeignConfig,
+    #[cgp(flatten, readonly)]
+    pub limits: inner::Limits<u32>,
+}
+
+#[derive(HasField)]
+pub struct Service<T> {
+    pub id: T,
+    #[cgp(flatten)]
+    pub app: App,
+}
+
+#[derive(HasField)]
+pub struct Pair {
+    #[cgp(flatten, readonly)]
+    pub inner: (u8, bool),
+}
+
+fn port<Context: HasField<symbol!("port"), Value = u16>>(context: &Context) -> u16 {
+    *context.get_field(PhantomData)
+}
+
+fn main() {
+    let mut app = App {
+        name: "app".to_owned(),
+        cache: Vec::new(),
+        config: ForeignConfig {
+            host: "localhost".to_owned(),
+            port: 8080,
+            secure: false,
+        },
+        limits: inner::Limits {
+            max_connections: 16,
+        },
+    };
+
+    assert_eq!(port(&app), 8080);
+    assert_eq!(app.get_field(PhantomData::<symbol!("name")>), "app");
+    assert_eq!(app.get_field(PhantomData::<symbol!("host")>), "localhost");
+    assert!(!*app.get_field(PhantomData::<symbol!("secure")>));
+    assert_eq!(
+        *app.get_field(PhantomData::<symbol!("max_connections")>),
+        16
+    );
+
+    *app.get_field_mut(PhantomData::<symbol!("port")>) = 9090;
+    assert_eq!(port(&app), 9090);
+
+    let mut service = Service { id: 1u64, app };
+
+    assert_eq!(port(&service), 9090);
+    assert_eq!(*service.get_field(PhantomData::<symbol!("id")>), 1);
+    assert_eq!(service.get_field(PhantomData::<symbol!("name")>), "app");
+
+    *service.get_field_mut(PhantomData::<symbol!("host")>) = "example.com".to_owned();
+    assert_eq!(service.app.config.host, "example.com");
+
+    let pair = Pair { inner: (1, true) };
+
+    assert_eq!(*pair.get_field(PhantomData::<Index<0>>), 1);
+    assert!(*pair.get_field(PhantomData::<Index<1>>));
+}
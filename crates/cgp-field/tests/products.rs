@@ -0,0 +1,101 @@
+use core::marker::PhantomData;
+
+use cgp_field::*;
+
+#[derive(Debug, Clone, PartialEq, HasFields)]
+pub struct Person {
+    pub name: String,
+    pub age: u8,
+}
+
+type PersonFields = Product![Field<symbol!("name"), String>, Field<symbol!("age"), u8>];
+
+type LabelFields<Value> = Product![Field<symbol!("label"), Value>];
+
+fn alice() -> PersonFields {
+    Person {
+        name: "Alice".to_owned(),
+        age: 30,
+    }
+    .into_fields()
+}
+
+#[test]
+fn test_take_field() {
+    let (age, rest) = alice().take_field(PhantomData::<symbol!("age")>);
+
+    assert_eq!(age, 30);
+    assert_eq!(rest, product![Field::from("Alice".to_owned())]);
+
+    let (name, rest) = alice().take_field(PhantomData::<symbol!("name")>);
+
+    assert_eq!(name, "Alice");
+    assert_eq!(rest, product![Field::<symbol!("age"), _>::from(30u8)]);
+}
+
+#[test]
+fn test_update_field() {
+    let (old_age, person) = alice().update_field(PhantomData::<symbol!("age")>, 31u8);
+
+    assert_eq!(old_age, 30);
+    assert_eq!(
+        Person::from_fields(person),
+        Person {
+            name: "Alice".to_owned(),
+            age: 31,
+        }
+    );
+
+    let (old_name, person) = alice().update_field(PhantomData::<symbol!("name")>, 'A');
+    let Cons(name, Cons(age, Nil)) = person;
+
+    assert_eq!(old_name, "Alice");
+    assert_eq!(name.value, 'A');
+    assert_eq!(age.value, 30);
+}
+
+#[test]
+fn test_remove_field() {
+    let rest: Product![Field<symbol!("age"), u8>] =
+        alice().remove_field(PhantomData::<symbol!("name")>);
+
+    assert_eq!(rest, product![Field::from(30u8)]);
+}
+
+#[test]
+fn test_concat_and_take_back() {
+    let name = product![Field::<symbol!("name"), _>::from("Alice".to_owned())];
+    let age = product![Field::<symbol!("age"), _>::from(30u8)];
+
+    let person: PersonFields = name.concat(age);
+
+    assert_eq!(person, alice());
+    assert_eq!(Nil.concat(product![1u8]), product![1u8]);
+
+    let (name, age) = person.take_field(PhantomData::<symbol!("name")>);
+
+    assert_eq!(name, "Alice");
+    assert_eq!(
+        Person::from_fields(product![Field::from(name)].concat(age)).age,
+        30
+    );
+}
+
+#[test]
+fn test_generic_field_operations() {
+    fn rename<Fields, Path, Value>(
+        fields: Fields,
+    ) -> <Fields::Remainder as Concat<LabelFields<Value>>>::Output
+    where
+        Fields: TakeField<symbol!("name"), Path, Value = Value>,
+        Fields::Remainder: Concat<LabelFields<Value>>,
+    {
+        let (value, rest) = fields.take_field(PhantomData);
+        rest.concat(product![Field::from(value)])
+    }
+
+    let Cons(age, Cons(label, Nil)) = rename(alice());
+
+    assert_eq!(age.value, 30);
+    assert_eq!(label.value, "Alice");
+}
@@ -0,0 +1,78 @@
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+
+use cgp_field::*;
+
+struct Name<Tag>(PhantomData<Tag>);
+
+impl<Tag: StaticFormat> Display for Name<Tag> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Tag::fmt(f)
+    }
+}
+
+fn name_of<Tag: StaticFormat>() -> String {
+    Name::<Tag>(PhantomData).to_string()
+}
+
+/// Lists the field names of a product of `Field`s.
+trait FieldNames {
+    fn field_names(names: &mut Vec<String>);
+}
+
+impl FieldNames for Nil {
+    fn field_names(_names: &mut Vec<String>) {}
+}
+
+impl<Tag: StaticFormat, Value, Tail: FieldNames> FieldNames for Cons<Field<Tag, Value>, Tail> {
+    fn field_names(names: &mut Vec<String>) {
+        names.push(name_of::<Tag>());
+        Tail::field_names(names);
+    }
+}
+
+#[derive(HasFields)]
+pub struct Config {
+    pub host: String,
+    #[cgp(rename = "listen_port")]
+    pub port: u16,
+}
+
+#[derive(HasFields)]
+pub struct Point(pub f64, pub f64);
+
+fn field_names<Context>() -> Vec<String>
+where
+    Context: HasFields,
+    Context::Fields: FieldNames,
+{
+    let mut names = Vec::new();
+    Context::Fields::field_names(&mut names);
+    names
+}
+
+#[test]
+fn test_symbol_names() {
+    assert_eq!(name_of::<symbol!("")>(), "");
+    assert_eq!(name_of::<symbol!("a")>(), "a");
+    assert_eq!(name_of::<symbol!("name")>(), "name");
+    assert_eq!(name_of::<symbol!("exactly8")>(), "exactly8");
+    assert_eq!(
+        name_of::<symbol!("a_field_name_longer_than_one_chunk")>(),
+        "a_field_name_longer_than_one_chunk"
+    );
+    assert_eq!(name_of::<symbol!("ünïcödé")>(), "ünïcödé");
+}
+
+#[test]
+fn test_char_and_index_names() {
+    assert_eq!(name_of::<Char<'x'>>(), "x");
+    assert_eq!(name_of::<Index<0>>(), "0");
+    assert_eq!(name_of::<Index<42>>(), "42");
+}
+
+#[test]
+fn test_field_names_of_structs() {
+    assert_eq!(field_names::<Config>(), ["host", "listen_port"]);
+    assert_eq!(field_names::<Point>(), ["0", "1"]);
+}
@@ -0,0 +1,125 @@
+use core::marker::PhantomData;
+
+use cgp_field::*;
+
+#[derive(Debug, Clone, PartialEq, HasVariants)]
+pub enum AppError {
+    NotFound(String),
+    Timeout(u64),
+    Denied(()),
+}
+
+#[derive(Debug, Clone, PartialEq, HasVariants)]
+pub enum IoError {
+    Timeout(u64),
+    NotFound(String),
+}
+
+type AppErrorVariants = Sum![
+    Field<symbol!("NotFound"), String>,
+    Field<symbol!("Timeout"), u64>,
+    Field<symbol!("Denied"), ()>,
+];
+
+type IoErrorVariants = Sum![Field<symbol!("Timeout"), u64>, Field<symbol!("NotFound"), String>];
+
+#[test]
+fn test_inject_and_extract() {
+    let error = AppErrorVariants::inject(PhantomData::<symbol!("Timeout")>, 30);
+
+    assert_eq!(
+        AppError::from_variants(error.clone()),
+        AppError::Timeout(30)
+    );
+
+    let rest = match error.extract(PhantomData::<symbol!("NotFound")>) {
+        Ok(_) => panic!("expected the remaining variants"),
+        Err(rest) => rest,
+    };
+
+    assert_eq!(rest.extract(PhantomData::<symbol!("Timeout")>), Ok(30));
+}
+
+#[test]
+fn test_handle_all_variants() {
+    fn describe(error: AppError) -> String {
+        let variants: AppErrorVariants = error.into_variants();
+
+        match variants.extract(PhantomData::<symbol!("NotFound")>) {
+            Ok(path) => format!("not found: {path}"),
+            Err(rest) => match rest.extract(PhantomData::<symbol!("Denied")>) {
+                Ok(()) => "denied".to_owned(),
+                Err(rest) => match rest.extract(PhantomData::<symbol!("Timeout")>) {
+                    Ok(seconds) => format!("timed out after {seconds}s"),
+                    Err(rest) => rest.finalize_extract(),
+                },
+            },
+        }
+    }
+
+    assert_eq!(describe(AppError::NotFound("a".to_owned())), "not found: a");
+    assert_eq!(describe(AppError::Timeout(5)), "timed out after 5s");
+    assert_eq!(describe(AppError::Denied(())), "denied");
+}
+
+#[test]
+fn test_widen() {
+    let errors = [IoError::Timeout(30), IoError::NotFound("b".to_owned())];
+
+    for error in errors {
+        let variants: IoErrorVariants = error.clone().into_variants();
+        let widened: AppErrorVariants = variants.widen();
+
+        let expected = match error {
+            IoError::Timeout(seconds) => AppError::Timeout(seconds),
+            IoError::NotFound(path) => AppError::NotFound(path),
+        };
+
+        assert_eq!(AppError::from_variants(widened), expected);
+    }
+}
+
+#[test]
+fn test_narrow() {
+    let error: AppErrorVariants = AppError::NotFound("c".to_owned()).into_variants();
+
+    match Narrow::<IoErrorVariants, _>::narrow(error) {
+        Ok(io_error) => assert_eq!(
+            IoError::from_variants(io_error),
+            IoError::NotFound("c".to_owned())
+        ),
+        Err(_) => panic!("expected an IO error"),
+    }
+
+    let error: AppErrorVariants = AppError::Denied(()).into_variants();
+
+    match Narrow::<IoErrorVariants, _>::narrow(error) {
+        Ok(_) => panic!("expected the remaining variants"),
+        Err(rest) => assert_eq!(rest.extract(PhantomData::<symbol!("Denied")>), Ok(())),
+    }
+}
+
+#[test]
+fn test_generic_narrow() {
+    fn handle_io<Error, Paths>(error: Error) -> Result<u64, Error>
+    where
+        Error: Narrow<IoErrorVariants, Paths> + Clone,
+    {
+        match Narrow::<IoErrorVariants, _>::narrow(error.clone()) {
+            Ok(io_error) => match IoError::from_variants(io_error) {
+                IoError::Timeout(seconds) => Ok(seconds),
+                IoError::NotFound(_) => Err(error),
+            },
+            Err(_) => Err(error),
+        }
+    }
+
+    let timeout: AppErrorVariants = AppError::Timeout(7).into_variants();
+    let denied: AppErrorVariants = AppError::Denied(()).into_variants();
+
+    assert_eq!(handle_io(timeout), Ok(7));
+    assert_eq!(
+        handle_io(denied).map_err(AppError::from_variants),
+        Err(AppError::Denied(()))
+    );
+}
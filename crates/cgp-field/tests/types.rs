@@ -0,0 +1,92 @@
+use core::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+use std::error::Error;
+
+use cgp_field::*;
+
+// A tag type that implements none of the standard traits.
+pub struct OpaqueTag;
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn default_of<T: Default>() -> T {
+    T::default()
+}
+
+#[test]
+fn test_field_traits_only_bounded_by_value() {
+    let field: Field<OpaqueTag, u32> = Field::from(1);
+    let copied = field;
+    #[allow(clippy::clone_on_copy)]
+    let cloned = field.clone();
+
+    assert_eq!(copied, cloned);
+    assert!(field < Field::from(2));
+    assert_eq!(field.cmp(&Field::from(1)), core::cmp::Ordering::Equal);
+    assert_eq!(hash_of(&field), hash_of(&1u32));
+    assert_eq!(Field::<OpaqueTag, u32>::default().value, 0);
+    assert_eq!(format!("{field:?}"), "Field { value: 1 }");
+}
+
+#[test]
+fn test_void_is_error() {
+    fn assert_error<E: Error + Send + Sync + 'static>() {}
+
+    assert_error::<Void>();
+
+    let result: Result<u8, Void> = Ok(1);
+    let boxed: Result<u8, Box<dyn Error>> = result.map_err(|e| e.into());
+
+    assert_eq!(boxed.unwrap(), 1);
+}
+
+#[test]
+fn test_index_traits() {
+    assert_eq!(format!("{:?}", Index::<3>), "Index<3>");
+    assert_eq!(Index::<3>, default_of::<Index<3>>());
+    assert_eq!(Index::<3>::new(), Index::<3>);
+    assert_eq!(hash_of(&Index::<3>), hash_of(&Index::<3>));
+}
+
+#[test]
+fn test_char_traits() {
+    assert_eq!(format!("{}", Char::<'n'>), "n");
+    assert!(Char::<'n'> == default_of::<Char<'n'>>());
+    assert!(Char::<'n'>::new() == Char::<'n'>);
+}
+
+#[test]
+fn test_symbol_display() {
+    assert_eq!(<symbol!("name")>::default().to_string(), "name");
+    assert_eq!(format!("{:?}", <symbol!("name")>::default()), r#""name""#);
+    assert_eq!(
+        format!("{:?}", product![<symbol!("a")>::default(), 1u8]),
+        r#"Cons("a", Cons(1, Nil))"#
+    );
+}
+
+#[test]
+fn test_product_traits() {
+    let product: Product![u8, &str] = product![1, "a"];
+
+    assert_eq!(product, Cons(1, Cons("a", Nil)));
+    assert!(product < product![2, "a"]);
+    assert_eq!(format!("{product:?}"), r#"Cons(1, Cons("a", Nil))"#);
+    assert_eq!(<Product![u8, bool]>::default(), product![0, false]);
+    assert_eq!(hash_of(&Nil), hash_of(&Nil));
+}
+
+#[test]
+fn test_sum_traits() {
+    let left: Sum![u8, bool] = Either::Left(1);
+    let right: Sum![u8, bool] = Either::Right(Either::Left(true));
+
+    assert_ne!(left, right);
+    assert!(left < right);
+    assert_eq!(format!("{left:?}"), "Left(1)");
+    assert_eq!(format!("{right:?}"), "Right(Left(true))");
+}
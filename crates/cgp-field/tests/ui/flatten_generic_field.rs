@@ -0,0 +1,10 @@
+use cgp_field::*;
+
+#[derive(HasField)]
+pub struct App<C> {
+    pub name: String,
+    #[cgp(flatten)]
+    pub config: C,
+}
+
+fn main() {}
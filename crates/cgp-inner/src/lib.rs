@@ -2,7 +2,7 @@
 
 extern crate alloc;
 
-use cgp_component::{cgp_component, DelegateComponent, HasComponents};
+use cgp_component::{cgp_component, DelegateComponent, HasComponents, IsProviderFor};
 
 #[cgp_component {
     name: InnerComponent,
@@ -1,13 +1,14 @@
 use core::marker::PhantomData;
 
-use cgp_component::{DelegateComponent, WithProvider};
+use cgp_component::{cgp_provider, DelegateComponent, IsProviderFor, WithProvider};
 
-use crate::traits::ProvideType;
+use crate::traits::{ProvideType, TypeComponent};
 
 pub struct UseDelegatedType<Components>(pub PhantomData<Components>);
 
 pub type WithDelegatedType<Components> = WithProvider<UseDelegatedType<Components>>;
 
+#[cgp_provider(TypeComponent)]
 impl<Context, Tag, Components, Type> ProvideType<Context, Tag> for UseDelegatedType<Components>
 where
     Components: DelegateComponent<Tag, Delegate = Type>,
@@ -1,13 +1,14 @@
 use core::marker::PhantomData;
 
-use cgp_component::WithProvider;
+use cgp_component::{cgp_provider, IsProviderFor, WithProvider};
 
-use crate::traits::has_type::ProvideType;
+use crate::traits::has_type::{ProvideType, TypeComponent};
 
 pub struct UseType<Type>(pub PhantomData<Type>);
 
 pub type WithType<Type> = WithProvider<UseType<Type>>;
 
+#[cgp_provider(TypeComponent)]
 impl<Context, Tag, Type> ProvideType<Context, Tag> for UseType<Type> {
     type Type = Type;
 }
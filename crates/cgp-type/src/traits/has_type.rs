@@ -1,24 +1,21 @@
-use cgp_component::{cgp_component, DelegateComponent, HasComponents, UseContext, UseDelegate};
+use cgp_component::{
+    cgp_component, cgp_provider, DelegateComponent, HasComponents, IsProviderFor, UseContext,
+    UseDelegate,
+};
 
 #[cgp_component {
     name: TypeComponent,
     provider: ProvideType,
+    derive_delegate: [Tag],
 }]
 pub trait HasType<Tag> {
     type Type;
 }
 
+#[cgp_provider(TypeComponent)]
 impl<Context, Tag> ProvideType<Context, Tag> for UseContext
 where
     Context: HasType<Tag>,
 {
     type Type = Context::Type;
 }
-
-impl<Context, Tag, Components, Type> ProvideType<Context, Tag> for UseDelegate<Components>
-where
-    Components: DelegateComponent<Tag>,
-    Components::Delegate: ProvideType<Context, Tag, Type = Type>,
-{
-    type Type = Type;
-}